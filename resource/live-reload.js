@@ -0,0 +1,135 @@
+// live reload for the grup preview, expects `grup.last_event_id`, `grup.interval`
+// and `grup.page` (the path the page was served at) to be set
+
+// patch `old` in place to look like `young`, reusing nodes so that scroll position,
+// focus and toggled <details> survive an update
+function morph(old, young) {
+    if (old.nodeType !== young.nodeType || old.nodeName !== young.nodeName) {
+        old.parentNode.replaceChild(young, old);
+        return;
+    }
+    if (old.nodeType !== Node.ELEMENT_NODE) {
+        if (old.nodeValue !== young.nodeValue) {
+            old.nodeValue = young.nodeValue;
+        }
+        return;
+    }
+    // content drawn in the browser (diagrams) is kept while its source is the same,
+    // its attributes (e.g. the line in data-sourcepos) are updated nonetheless
+    var drawn = old.hasAttribute("data-source")
+        && old.getAttribute("data-source") === young.getAttribute("data-source");
+    // the reader decides whether a <details> is expanded, not the document
+    var open = old.nodeName === "DETAILS" ? old.open : null;
+    for (var i = old.attributes.length - 1; i >= 0; i--) {
+        var name = old.attributes[i].name;
+        if (!young.hasAttribute(name) && !(drawn && name === "data-drawn")) {
+            old.removeAttribute(name);
+        }
+    }
+    for (var i = 0; i < young.attributes.length; i++) {
+        var attr = young.attributes[i];
+        if (old.getAttribute(attr.name) !== attr.value) {
+            old.setAttribute(attr.name, attr.value);
+        }
+    }
+    if (open !== null) {
+        old.open = open;
+    }
+    if (drawn) {
+        return;
+    }
+    var old_children = Array.prototype.slice.call(old.childNodes);
+    var young_children = Array.prototype.slice.call(young.childNodes);
+    for (var i = 0; i < young_children.length; i++) {
+        if (i < old_children.length) {
+            morph(old_children[i], young_children[i]);
+        } else {
+            old.appendChild(young_children[i]);
+        }
+    }
+    for (var i = young_children.length; i < old_children.length; i++) {
+        old.removeChild(old_children[i]);
+    }
+}
+
+// fetch the freshly rendered content and patch it into the page
+function update_content() {
+    var xhr = new XMLHttpRequest();
+    xhr.onreadystatechange = function () {
+        if (this.readyState === 4) {
+            if (this.status === 200) {
+                var template = document.createElement("template");
+                template.innerHTML = this.responseText.trim();
+                // the content, and the table of contents if a template placed it elsewhere
+                var parts = Array.prototype.slice.call(template.content.children);
+                for (var i = 0; i < parts.length; i++) {
+                    var old = document.getElementById(parts[i].id);
+                    if (old) {
+                        morph(old, parts[i]);
+                    }
+                }
+                document.dispatchEvent(new Event("grup:updated"));
+            } else if (this.status === 404) {
+                // an editor is replacing the file right now, the next event brings it back
+            } else {
+                location.reload();
+            }
+        }
+    }
+    xhr.open("GET", "/fragment" + grup.page, true);
+    xhr.send();
+}
+
+// scroll to the block containing `line` of the markdown file
+function scroll_to_line(line) {
+    var blocks = document.querySelectorAll("#grup-content [data-sourcepos]");
+    var target = null;
+    for (var i = 0; i < blocks.length; i++) {
+        if (parseInt(blocks[i].getAttribute("data-sourcepos"), 10) > line) {
+            break;
+        }
+        target = blocks[i];
+    }
+    if (target !== null) {
+        target.scrollIntoView({ behavior: "smooth", block: "start" });
+    }
+}
+
+// fallback for browsers without EventSource
+function reload_check() {
+    var xhr = new XMLHttpRequest();
+    xhr.overrideMimeType("text/plain");
+    xhr.timeout = grup.interval;
+    xhr.onreadystatechange = function () {
+        if (this.readyState === 4) {
+            if (this.status === 200) {
+                if (this.responseText == "yes") {
+                    location.reload();
+                } else {
+                    reload_check();
+                }
+            }
+        }
+    }
+    xhr.ontimeout = function () {
+        reload_check();
+    }
+    xhr.open("GET", "/update", true);
+    xhr.send();
+}
+
+if (window.EventSource && window.HTMLTemplateElement) {
+    var events = new EventSource("/events?since=" + grup.last_event_id
+        + "&page=" + encodeURIComponent(grup.page));
+    events.addEventListener("reload", update_content);
+    // the cursor of an editor moved
+    events.addEventListener("scroll", function (e) {
+        scroll_to_line(parseInt(e.data, 10));
+    });
+    // the stylesheet or template changed
+    events.addEventListener("refresh", function () {
+        location.reload();
+    });
+} else {
+    reload_check();
+}
@@ -0,0 +1,45 @@
+// draws the mermaid diagrams of the page, loading /mermaid.min.js the first time there are any.
+// Diagrams are redrawn when live reload replaced their source
+
+function mermaid_theme() {
+    var dark = document.getElementById("grup-dark");
+    return dark && window.matchMedia(dark.media).matches ? "dark" : "default";
+}
+
+function mermaid_draw() {
+    var diagrams = document.querySelectorAll(".mermaid-diagram:not([data-drawn])");
+    if (diagrams.length === 0) {
+        return;
+    }
+    if (!window.mermaid) {
+        if (!document.getElementById("grup-mermaid")) {
+            var script = document.createElement("script");
+            script.id = "grup-mermaid";
+            script.src = "/mermaid.min.js";
+            script.onload = mermaid_draw;
+            document.head.appendChild(script);
+        }
+        return;
+    }
+    mermaid.initialize({ startOnLoad: false, theme: mermaid_theme() });
+    Array.prototype.forEach.call(diagrams, function (diagram, i) {
+        var show = function (svg) {
+            diagram.innerHTML = svg;
+        };
+        diagram.setAttribute("data-drawn", "");
+        try {
+            var id = "grup-mermaid-" + Date.now() + "-" + i;
+            var result = mermaid.render(id, diagram.getAttribute("data-source"), show);
+            // newer versions return a promise instead of calling back
+            if (result && result.then) {
+                result.then(function (r) { show(r.svg); }, function (e) { console.error(e); });
+            }
+        } catch (e) {
+            // the source stays visible
+            console.error(e);
+        }
+    });
+}
+
+document.addEventListener("grup:updated", mermaid_draw);
+mermaid_draw();
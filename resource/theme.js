@@ -0,0 +1,45 @@
+// switches between the light, dark and auto (following the system) theme.
+// The dark stylesheet is the <link id="grup-dark"> carrying the --theme default
+// in data-theme, the reader's choice is remembered in localStorage
+
+var GRUP_THEMES = ["auto", "light", "dark"];
+var GRUP_THEME_MEDIA = { auto: "(prefers-color-scheme: dark)", light: "not all", dark: "all" };
+var GRUP_THEME_LABELS = { auto: "◐ auto", light: "☀ light", dark: "☾ dark" };
+
+function theme_get() {
+    var theme = null;
+    try {
+        theme = localStorage.getItem("grup-theme");
+    } catch (e) {
+        // storage is disabled, stay with the default
+    }
+    if (GRUP_THEMES.indexOf(theme) < 0) {
+        theme = document.getElementById("grup-dark").getAttribute("data-theme");
+    }
+    return theme;
+}
+
+function theme_apply(theme) {
+    document.getElementById("grup-dark").media = GRUP_THEME_MEDIA[theme];
+    var toggle = document.getElementById("grup-theme-toggle");
+    if (toggle) {
+        toggle.textContent = GRUP_THEME_LABELS[theme];
+    }
+}
+
+function theme_toggle() {
+    var next = GRUP_THEMES[(GRUP_THEMES.indexOf(theme_get()) + 1) % GRUP_THEMES.length];
+    try {
+        localStorage.setItem("grup-theme", next);
+    } catch (e) {
+        // only lasts until the page is reloaded then
+    }
+    theme_apply(next);
+}
+
+// applied right away, so the page does not flash in the wrong theme
+theme_apply(theme_get());
+document.addEventListener("DOMContentLoaded", function () {
+    document.getElementById("grup-theme-toggle").addEventListener("click", theme_toggle);
+    theme_apply(theme_get());
+});
@@ -0,0 +1,21 @@
+// highlights the section scrolled to in the table of contents sidebar
+
+function toc_highlight() {
+    var links = document.querySelectorAll(".toc-sidebar a");
+    var current = links.length > 0 ? links[0] : null;
+    for (var i = 0; i < links.length; i++) {
+        links[i].classList.remove("active");
+        // the id sits on the permalink at the start of the heading
+        var anchor = document.getElementById(links[i].getAttribute("href").substring(1));
+        if (anchor && anchor.getBoundingClientRect().top <= 16) {
+            current = links[i];
+        }
+    }
+    if (current !== null) {
+        current.classList.add("active");
+    }
+}
+
+window.addEventListener("scroll", toc_highlight);
+document.addEventListener("grup:updated", toc_highlight);
+toc_highlight();
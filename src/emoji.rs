@@ -0,0 +1,80 @@
+//! `:shortcode:` emoji, from the table of github's emoji compiled into grup
+
+use comrak::nodes::{AstNode, NodeValue};
+
+/// Replace the known shortcodes in a text, None if there are none
+pub fn expand(text: &str) -> Option<String> {
+    let mut expanded = String::with_capacity(text.len());
+    let mut rest = text;
+    let mut replaced = false;
+    while let Some(start) = rest.find(':') {
+        let after = &rest[start + 1..];
+        let length = after
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '+' || c == '-'))
+            .unwrap_or(after.len());
+        if length > 0 && after[length..].starts_with(':') {
+            if let Some(emoji) = gh_emoji::get(&after[..length]) {
+                expanded.push_str(&rest[..start]);
+                expanded.push_str(emoji);
+                rest = &after[length + 1..];
+                replaced = true;
+                continue;
+            }
+        }
+        expanded.push_str(&rest[..=start]);
+        rest = after;
+    }
+    if !replaced {
+        return None;
+    }
+    expanded.push_str(rest);
+    Some(expanded)
+}
+
+/// Replace shortcodes in the text of the document, leaving code and html alone
+pub fn expand_shortcodes<'a>(root: &'a AstNode<'a>) {
+    for node in root.descendants() {
+        let mut ast = node.data.borrow_mut();
+        if let NodeValue::Text(ref mut literal) = ast.value {
+            // the parser may have split the text at the colons
+            while let Some(next) = node.next_sibling() {
+                match next.data.borrow().value {
+                    NodeValue::Text(ref more) => literal.extend_from_slice(more),
+                    _ => break,
+                }
+                next.detach();
+            }
+            if let Some(expanded) = expand(&String::from_utf8_lossy(literal)) {
+                *literal = expanded.into_bytes();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_shortcodes() {
+        assert_eq!(
+            expand(":+1: done :rocket:").as_deref(),
+            Some("\u{1f44d} done \u{1f680}")
+        );
+        assert_eq!(
+            expand(":tada::tada:").as_deref(),
+            Some("\u{1f389}\u{1f389}")
+        );
+    }
+
+    #[test]
+    fn other_colons_stay() {
+        assert_eq!(expand("at 10:30:45"), None);
+        assert_eq!(expand("a :nonexistent_emoji: b"), None);
+        assert_eq!(expand("key: value"), None);
+        assert_eq!(
+            expand("10:30: :smile:").as_deref(),
+            Some("10:30: \u{1f604}")
+        );
+    }
+}
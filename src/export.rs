@@ -0,0 +1,89 @@
+//! `grup export`: write the rendered markdown to a standalone html file
+
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use structopt::StructOpt;
+
+use crate::theme::Theme;
+use crate::{article, page, render, DEFAULT_CSS, GRUP_CSS};
+
+#[derive(Debug, StructOpt)]
+#[structopt(name = "grup export")]
+/// grup export - render a markdown file into a standalone html file
+pub struct ExportCfg {
+    #[structopt(name = "markdown_file", parse(from_os_str))]
+    /// The markdown file to be rendered
+    md_file: PathBuf,
+    #[structopt(
+        short = "o",
+        long = "output",
+        parse(from_os_str),
+        help = "the html file to write, stdout if omitted"
+    )]
+    output: Option<PathBuf>,
+    #[structopt(
+        long = "self-contained",
+        help = "embed local images as data uris, so the html file renders on its own"
+    )]
+    self_contained: bool,
+    #[structopt(
+        long = "theme",
+        default_value = "auto",
+        possible_values = Theme::NAMES,
+        help = "the color theme, auto follows the system preference of the reader"
+    )]
+    theme: Theme,
+    #[structopt(flatten)]
+    extensions: render::Extensions,
+}
+
+fn inline_stylesheet(theme: Theme) -> String {
+    format!(
+        "<style>\n{}\n{}\n</style>\n{}",
+        String::from_utf8_lossy(DEFAULT_CSS),
+        String::from_utf8_lossy(GRUP_CSS),
+        theme.inline_stylesheet()
+    )
+}
+
+/// Render into a single html document with all local images embedded.
+/// Images are resolved relative to `base` and must not lie outside of it
+pub fn standalone_page(
+    title: &str,
+    md: &str,
+    extensions: &render::Extensions,
+    base: &Path,
+    theme: Theme,
+) -> String {
+    let article = article(md, extensions, Some(base), false);
+    let title = article.title.as_ref().map_or(title, String::as_str);
+    page(title, &inline_stylesheet(theme), &article.html, "")
+}
+
+pub fn export(cfg: &ExportCfg) -> io::Result<()> {
+    let md = fs::read_to_string(&cfg.md_file)
+        .map_err(|e| io::Error::new(e.kind(), format!("Cannot read {:?}: {}", cfg.md_file, e)))?;
+    let title = cfg.md_file.to_string_lossy();
+    let document = if cfg.self_contained {
+        let base = match cfg.md_file.parent() {
+            Some(parent) if parent != Path::new("") => parent.canonicalize()?,
+            _ => std::env::current_dir()?,
+        };
+        standalone_page(&title, &md, &cfg.extensions, &base, cfg.theme)
+    } else {
+        let article = article(&md, &cfg.extensions, None, false);
+        let title = article.title.as_ref().map_or(&*title, String::as_str);
+        page(title, &inline_stylesheet(cfg.theme), &article.html, "")
+    };
+
+    match cfg.output {
+        Some(ref output) => {
+            fs::write(output, document)?;
+            info!("wrote {:?}", output);
+        }
+        None => io::stdout().write_all(document.as_bytes())?,
+    }
+    Ok(())
+}
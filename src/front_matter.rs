@@ -0,0 +1,197 @@
+//! YAML (`---`) and TOML (`+++`) front matter, shown as a table the way github does
+
+use serde_yaml::Value as Yaml;
+use toml::Value as Toml;
+
+use crate::render::escape_html;
+
+/// The values of YAML and TOML, as far as the table is concerned
+enum Meta {
+    Scalar(String),
+    List(Vec<Meta>),
+    Map(Vec<(String, Meta)>),
+}
+
+impl Meta {
+    fn from_yaml(value: Yaml) -> Meta {
+        match value {
+            Yaml::Null => Meta::Scalar(String::new()),
+            Yaml::Bool(b) => Meta::Scalar(b.to_string()),
+            Yaml::Number(n) => Meta::Scalar(n.to_string()),
+            Yaml::String(s) => Meta::Scalar(s),
+            Yaml::Sequence(items) => Meta::List(items.into_iter().map(Meta::from_yaml).collect()),
+            Yaml::Mapping(map) => Meta::Map(
+                map.into_iter()
+                    .map(|(key, value)| {
+                        let key = match Meta::from_yaml(key) {
+                            Meta::Scalar(key) => key,
+                            _ => String::from("?"),
+                        };
+                        (key, Meta::from_yaml(value))
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    fn from_toml(value: Toml) -> Meta {
+        match value {
+            Toml::String(s) => Meta::Scalar(s),
+            Toml::Array(items) => Meta::List(items.into_iter().map(Meta::from_toml).collect()),
+            Toml::Table(table) => Meta::Map(
+                table
+                    .into_iter()
+                    .map(|(key, value)| (key, Meta::from_toml(value)))
+                    .collect(),
+            ),
+            other => Meta::Scalar(other.to_string()),
+        }
+    }
+
+    /// A table with the keys as head and the values as a single row, nested values nest tables
+    fn to_html(&self, html: &mut String) {
+        match self {
+            Meta::Scalar(s) => html.push_str(&escape_html(s)),
+            Meta::List(items) => {
+                html.push_str("<table><tbody><tr>");
+                for item in items {
+                    html.push_str("<td>");
+                    item.to_html(html);
+                    html.push_str("</td>");
+                }
+                html.push_str("</tr></tbody></table>");
+            }
+            Meta::Map(entries) => {
+                html.push_str("<table><thead><tr>");
+                for (key, _) in entries {
+                    html.push_str(&format!("<th>{}</th>", escape_html(key)));
+                }
+                html.push_str("</tr></thead><tbody><tr>");
+                for (_, value) in entries {
+                    html.push_str("<td>");
+                    value.to_html(html);
+                    html.push_str("</td>");
+                }
+                html.push_str("</tr></tbody></table>");
+            }
+        }
+    }
+}
+
+pub struct FrontMatter {
+    /// the `title` key
+    pub title: Option<String>,
+    /// the metadata table
+    pub html: String,
+}
+
+/// Split the front matter off a document. It is replaced by empty lines,
+/// so the line numbers of the markdown stay the same
+pub fn split(md: &str) -> Option<(FrontMatter, String)> {
+    let mut lines = md.lines();
+    let delimiter = match lines.next().map(str::trim_end) {
+        Some("---") => "---",
+        Some("+++") => "+++",
+        _ => return None,
+    };
+    let mut front_matter = String::new();
+    let mut length = 1;
+    loop {
+        let line = lines.next()?;
+        length += 1;
+        let end = line.trim_end();
+        // yaml documents may also end with ...
+        if end == delimiter || (delimiter == "---" && end == "...") {
+            break;
+        }
+        front_matter.push_str(line);
+        front_matter.push('\n');
+    }
+
+    let parsed = if delimiter == "---" {
+        serde_yaml::from_str::<Yaml>(&front_matter)
+            .map(Meta::from_yaml)
+            .map_err(|e| e.to_string())
+    } else {
+        toml::from_str::<Toml>(&front_matter)
+            .map(Meta::from_toml)
+            .map_err(|e| e.to_string())
+    };
+    let mut html = String::new();
+    let mut title = None;
+    match parsed {
+        Ok(Meta::Map(entries)) => {
+            title = entries.iter().find_map(|(key, value)| match value {
+                Meta::Scalar(title) if key == "title" => Some(title.clone()),
+                _ => None,
+            });
+            if !entries.is_empty() {
+                Meta::Map(entries).to_html(&mut html);
+            }
+        }
+        // an empty front matter
+        Ok(Meta::Scalar(ref s)) if s.is_empty() => (),
+        Ok(other) => other.to_html(&mut html),
+        Err(e) => {
+            warn!("cannot parse front matter: {}", e);
+            html = format!("<pre><code>{}</code></pre>", escape_html(&front_matter));
+        }
+    }
+
+    let mut rest = "\n".repeat(length);
+    for line in lines {
+        rest.push_str(line);
+        rest.push('\n');
+    }
+    Some((FrontMatter { title, html }, rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn yaml() {
+        let (front_matter, rest) = split("---\ntitle: Hi\ntags: [a, b]\n---\n# Body\n").unwrap();
+        assert_eq!(front_matter.title.as_deref(), Some("Hi"));
+        assert!(front_matter.html.contains("tags"), "{}", front_matter.html);
+        // the body stays on its line
+        assert_eq!(rest, "\n\n\n\n# Body\n");
+    }
+
+    #[test]
+    fn yaml_ending_with_dots() {
+        let (front_matter, rest) = split("---\ntitle: Hi\n...\ntext\n").unwrap();
+        assert_eq!(front_matter.title.as_deref(), Some("Hi"));
+        assert_eq!(rest, "\n\n\ntext\n");
+    }
+
+    #[test]
+    fn toml() {
+        let (front_matter, rest) =
+            split("+++\ntitle = \"Hi\"\n[extra]\nx = 1\n+++\ntext\n").unwrap();
+        assert_eq!(front_matter.title.as_deref(), Some("Hi"));
+        assert!(front_matter.html.contains("extra"), "{}", front_matter.html);
+        assert_eq!(rest.lines().count(), 6);
+    }
+
+    #[test]
+    fn no_front_matter() {
+        assert!(split("# Title\n---\n").is_none());
+        assert!(split("text\n\n---\ntitle: x\n---\n").is_none());
+        // a thematic break without an end
+        assert!(split("---\n\ntext\n").is_none());
+    }
+
+    #[test]
+    fn invalid_front_matter_is_shown() {
+        let (front_matter, rest) = split("---\n: [\n---\ntext\n").unwrap();
+        assert_eq!(front_matter.title, None);
+        assert!(
+            front_matter.html.starts_with("<pre><code>"),
+            "{}",
+            front_matter.html
+        );
+        assert_eq!(rest, "\n\n\ntext\n");
+    }
+}
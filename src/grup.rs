@@ -1,30 +1,56 @@
 #[macro_use]
 extern crate log;
 
-use std::io;
+mod alerts;
+mod emoji;
+mod export;
+mod front_matter;
+mod headings;
+mod highlight;
+mod index;
+mod inline;
+mod math;
+mod mermaid;
+mod render;
+mod template;
+mod theme;
+
+use std::borrow::Cow;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::io::{self, BufRead};
 use std::net::IpAddr;
 use std::path::{Path, PathBuf};
+use std::sync::mpsc::Sender as StdSender;
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
-use comrak::ComrakOptions;
 use hyper::service::{make_service_fn, service_fn};
-use hyper::{Body, Request, Response, Server, StatusCode};
-use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use hyper::{Body, Method, Request, Response, Server, StatusCode};
+use notify::event::ModifyKind;
+use notify::{Event, EventKind, PollWatcher, RecommendedWatcher, RecursiveMode, Watcher};
+use percent_encoding::percent_decode_str;
 use structopt::StructOpt;
 
+use tokio::future::FutureExt;
 use tokio_fs::File;
 use tokio_io::AsyncReadExt;
+use tokio_sync::mpsc::{self, UnboundedSender};
 use tokio_sync::oneshot::{self, Sender};
 // #[macro_use]
 // use tokio::prelude::*;
 // use tokio_fs::File;
 
 #[derive(Debug, StructOpt)]
+#[structopt(after_help = "Run `grup export --help` for writing the rendered markdown to a file.")]
 /// grup - an offline github markdown previewer
 struct Cfg {
     #[structopt(name = "markdown_file", parse(from_os_str))]
-    /// The markdown file to be served
+    /// The markdown file to be served, a directory to browse the markdown files in,
+    /// or - to read the markdown from stdin
     md_file: PathBuf,
+    #[structopt(skip)]
+    /// The directory everything is served from, set up in main
+    root: PathBuf,
     #[structopt(
         long = "port",
         default_value = "8000",
@@ -48,12 +74,215 @@ struct Cfg {
         help = "serve static files relative to markdown file"
     )]
     serve_static: bool,
+    #[structopt(
+        long = "toc",
+        help = "show a table of contents sidebar next to the document"
+    )]
+    toc: bool,
+    #[structopt(
+        long = "theme",
+        default_value = "auto",
+        possible_values = theme::Theme::NAMES,
+        help = "the color theme, auto follows the system preference"
+    )]
+    theme: theme::Theme,
+    #[structopt(
+        long = "css",
+        parse(from_os_str),
+        help = "a stylesheet applied after the github one"
+    )]
+    css: Option<PathBuf>,
+    #[structopt(
+        long = "replace-css",
+        requires = "css",
+        help = "use the --css stylesheet instead of the github one"
+    )]
+    replace_css: bool,
+    #[structopt(
+        long = "template",
+        parse(from_os_str),
+        help = "an html template for the page, with {{title}}, {{content}}, {{toc}}, {{head}} and {{script}}"
+    )]
+    template: Option<PathBuf>,
+    #[structopt(
+        long = "delimiter",
+        help = "with - as markdown file, keep reading documents from stdin, each ending at a line equal to this"
+    )]
+    delimiter: Option<String>,
+    #[structopt(
+        long = "debounce",
+        default_value = "100",
+        help = "how long in ms files have to stay unchanged before the browsers reload"
+    )]
+    debounce: u64,
+    #[structopt(
+        long = "poll",
+        help = "check files for changes every <poll> ms instead of using the native file watcher, for network and container file systems"
+    )]
+    poll: Option<u64>,
+    #[structopt(flatten)]
+    extensions: render::Extensions,
+}
+
+/// An event pushed to the browsers on the /events stream
+#[derive(Debug, Clone)]
+struct ServerEvent {
+    id: u64,
+    name: &'static str,
+    data: String,
+}
+
+impl ServerEvent {
+    /// Encode as text/event-stream message
+    fn encode(&self) -> String {
+        let mut msg = format!("id: {}\nevent: {}\n", self.id, self.name);
+        for line in self.data.lines() {
+            msg.push_str("data: ");
+            msg.push_str(line);
+            msg.push('\n');
+        }
+        if self.data.is_empty() {
+            msg.push_str("data:\n");
+        }
+        msg.push('\n');
+        msg
+    }
+}
+
+/// An open /events stream
+struct Stream {
+    id: u64,
+    /// the markdown file (or directory index) shown by the browser
+    page: PathBuf,
+    /// sending fails once the browser went away, the stream then removes itself
+    tx: UnboundedSender<ServerEvent>,
+}
+
+/// The browsers waiting to be told about changes
+#[derive(Default)]
+struct Clients {
+    /// id of the last event sent, reconnecting browsers report theirs via Last-Event-ID
+    last_id: u64,
+    /// id of the last change of each page
+    changed: HashMap<PathBuf, u64>,
+    /// id of the last change of the stylesheet or template
+    refreshed: u64,
+    next_stream_id: u64,
+    streams: Vec<Stream>,
+    /// parked /update long polls of pages without EventSource support
+    polls: Vec<Sender<()>>,
+    /// unsaved editor buffers shown in place of the files until they change on disk
+    buffers: HashMap<PathBuf, String>,
+    /// the pages referencing each local file, reloaded when it changes
+    dependencies: HashMap<PathBuf, HashSet<PathBuf>>,
+    /// watches the referenced files as well, None if watching failed
+    watcher: Option<FileWatcher>,
+}
+
+impl Clients {
+    /// Remember the files `page` references now, watching those not watched yet
+    fn depend(&mut self, page: &Path, assets: Vec<PathBuf>) {
+        for pages in self.dependencies.values_mut() {
+            pages.remove(page);
+        }
+        self.dependencies.retain(|_, pages| !pages.is_empty());
+        for asset in assets {
+            if let Some(ref mut watcher) = self.watcher {
+                if let Err(e) = watcher.watch_file(&asset) {
+                    warn!("cannot watch {:?}: {:?}", asset, e);
+                }
+            }
+            self.dependencies
+                .entry(asset)
+                .or_default()
+                .insert(page.to_owned());
+        }
+    }
+
+    /// The pages referencing `file`
+    fn dependents(&self, file: &Path) -> Vec<PathBuf> {
+        self.dependencies
+            .get(file)
+            .map(|pages| pages.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Tell the browsers showing `page` to reload, dropping those that went away
+    fn reload(&mut self, page: &Path) {
+        self.last_id += 1;
+        self.changed.insert(page.to_owned(), self.last_id);
+        let event = ServerEvent {
+            id: self.last_id,
+            name: "reload",
+            data: String::new(),
+        };
+        for stream in self.streams.iter_mut().filter(|stream| stream.page == page) {
+            let _ = stream.tx.try_send(event.clone());
+        }
+        // the long polls do not know their page
+        for tx in self.polls.drain(..) {
+            // ignore errors
+            let _ = tx.send(());
+        }
+    }
+
+    /// Tell every browser to load the whole page again, as what is around the document changed
+    fn refresh(&mut self) {
+        self.last_id += 1;
+        self.refreshed = self.last_id;
+        let event = ServerEvent {
+            id: self.last_id,
+            name: "refresh",
+            data: String::new(),
+        };
+        for stream in self.streams.iter_mut() {
+            let _ = stream.tx.try_send(event.clone());
+        }
+        for tx in self.polls.drain(..) {
+            // ignore errors
+            let _ = tx.send(());
+        }
+    }
+
+    /// Tell the browsers showing `page`, or all of them, to scroll to the block at `line`
+    fn scroll(&mut self, page: Option<&Path>, line: u32) {
+        self.last_id += 1;
+        let event = ServerEvent {
+            id: self.last_id,
+            name: "scroll",
+            data: line.to_string(),
+        };
+        for stream in self.streams.iter_mut() {
+            if page.is_none_or(|page| stream.page == page) {
+                let _ = stream.tx.try_send(event.clone());
+            }
+        }
+    }
+
+    /// Id of the last change of `page`, 0 if it never changed
+    fn changed(&self, page: &Path) -> u64 {
+        self.changed.get(page).cloned().unwrap_or(0)
+    }
 }
 
 type CfgPtr = Arc<Cfg>;
-type SenderListPtr = Arc<Mutex<Vec<Sender<()>>>>;
+type ClientsPtr = Arc<Mutex<Clients>>;
 
 const DEFAULT_CSS: &[u8] = include_bytes!("../resource/github-markdown.css");
+/// Styles for what grup adds to the document, served after the github stylesheet
+const GRUP_CSS: &[u8] = include_bytes!("../resource/grup.css");
+/// The file name the markdown read from stdin is served as
+const STDIN_PAGE: &str = "<stdin>";
+const LIVE_RELOAD_JS: &str = include_str!("../resource/live-reload.js");
+const TOC_JS: &str = include_str!("../resource/toc.js");
+/// how often to send a comment on idle event streams to detect closed connections
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(15);
+/// how many debounce windows changes wait at most while other files keep changing
+const MAX_DEBOUNCE_WINDOWS: u32 = 10;
+/// How often files are checked for changes in ms, when they cannot be watched natively
+const DEFAULT_POLL_INTERVAL: u64 = 1000;
+/// how long browsers wait before reconnecting a dropped event stream in ms
+const RECONNECT_DELAY: u32 = 1000;
 
 fn not_found() -> Result<Response<Body>, hyper::Error> {
     let mut response = Response::builder();
@@ -63,15 +292,17 @@ fn not_found() -> Result<Response<Body>, hyper::Error> {
         .expect("invalid response builder"))
 }
 
-async fn update(updaters: SenderListPtr) -> Result<Response<Body>, hyper::Error> {
+async fn update(clients: ClientsPtr) -> Result<Response<Body>, hyper::Error> {
     let mut response = Response::builder();
     response.header("Cache-Control", "no-cache, no-store, must-revalidate");
     response.header("Pragma", "no-cache");
     response.header("Expires", "0");
 
     let (tx, rx) = oneshot::channel();
-    if let Ok(mut updaters) = updaters.lock() {
-        updaters.push(tx);
+    if let Ok(mut clients) = clients.lock() {
+        // polls of closed tabs are never answered, so clean them up here
+        clients.polls.retain(|tx| !tx.is_closed());
+        clients.polls.push(tx);
     } else {
         error!("Internal error: mutex poisoned");
     }
@@ -82,30 +313,341 @@ async fn update(updaters: SenderListPtr) -> Result<Response<Body>, hyper::Error>
         .expect("invalid response builder"))
 }
 
-async fn md_file(cfg: CfgPtr) -> Result<Response<Body>, hyper::Error> {
+/// Whether a request was sent by another web site, which must not control the preview.
+/// Editors send neither header, browsers send them with every cross-site POST
+fn cross_site(req: &Request<Body>) -> bool {
+    let header = |name| {
+        req.headers()
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+    };
+    if let Some(site) = header("Sec-Fetch-Site") {
+        if site != "same-origin" && site != "none" {
+            return true;
+        }
+    }
+    match header("Origin") {
+        Some(origin) => header("Host").is_none_or(|host| origin != format!("http://{}", host)),
+        None => false,
+    }
+}
+
+fn forbidden() -> Result<Response<Body>, hyper::Error> {
     let mut response = Response::builder();
-    response.header("Content-type", "text/html");
+    response.status(StatusCode::FORBIDDEN);
+    Ok(response
+        .body(Body::from(""))
+        .expect("invalid response builder"))
+}
 
-    let content = if let Ok(mut file) = File::open(&cfg.md_file).await {
-        let mut buf = String::new();
-        if file.read_to_string(&mut buf).await.is_ok() {
-            let mut options = ComrakOptions::default();
-            options.hardbreaks = true;
-            comrak::markdown_to_html(&buf, &options)
-        } else {
-            return not_found();
+/// Value of a query parameter, percent decoded
+fn query_param<'a>(req: &'a Request<Body>, name: &str) -> Option<Cow<'a, str>> {
+    req.uri()
+        .query()?
+        .split('&')
+        .filter_map(|pair| {
+            let mut kv = pair.splitn(2, '=');
+            Some((kv.next()?, kv.next()?))
+        })
+        .find(|(k, _)| *k == name)
+        .and_then(|(_, v)| percent_decode_str(v).decode_utf8().ok())
+}
+
+/// `POST /cursor?line=N` scrolls the browsers to line N of the markdown file,
+/// with `&page=/path.md` only those showing that page when browsing a directory
+async fn cursor(
+    cfg: CfgPtr,
+    clients: ClientsPtr,
+    req: Request<Body>,
+) -> Result<Response<Body>, hyper::Error> {
+    let mut response = Response::builder();
+    if req.method() != Method::POST {
+        response.status(StatusCode::METHOD_NOT_ALLOWED);
+        response.header("Allow", "POST");
+        return Ok(response
+            .body(Body::from(""))
+            .expect("invalid response builder"));
+    }
+    if cross_site(&req) {
+        return forbidden();
+    }
+    let line = match query_param(&req, "line").and_then(|line| line.parse().ok()) {
+        Some(line) => line,
+        None => {
+            response.status(StatusCode::BAD_REQUEST);
+            return Ok(response
+                .body(Body::from("expected ?line=<number>"))
+                .expect("invalid response builder"));
+        }
+    };
+    let page = query_param(&req, "page").and_then(|page| page_path(&cfg, &page));
+    if let Ok(mut clients) = clients.lock() {
+        clients.scroll(page.as_deref(), line);
+    } else {
+        error!("Internal error: mutex poisoned");
+    }
+    response.status(StatusCode::NO_CONTENT);
+    Ok(response
+        .body(Body::from(""))
+        .expect("invalid response builder"))
+}
+
+/// `POST /render` with the markdown as body shows it in place of the file until the file
+/// changes on disk, so editors can preview unsaved buffers. `?page=/path.md` picks the file
+/// when browsing a directory
+async fn render_buffer(
+    cfg: CfgPtr,
+    clients: ClientsPtr,
+    req: Request<Body>,
+) -> Result<Response<Body>, hyper::Error> {
+    let mut response = Response::builder();
+    if req.method() != Method::POST {
+        response.status(StatusCode::METHOD_NOT_ALLOWED);
+        response.header("Allow", "POST");
+        return Ok(response
+            .body(Body::from(""))
+            .expect("invalid response builder"));
+    }
+    // other pages could show anything in the preview, with its access to the served files
+    if cross_site(&req) {
+        return forbidden();
+    }
+    let page = match query_param(&req, "page") {
+        Some(page) => page_path(&cfg, &page),
+        None => Some(cfg.md_file.clone()),
+    };
+    let page = match page {
+        Some(ref page) if !page.is_dir() => page.clone(),
+        _ => return not_found(),
+    };
+
+    let mut body = req.into_body();
+    let mut buf = Vec::new();
+    while let Some(chunk) = body.next().await {
+        buf.extend_from_slice(&chunk?);
+    }
+    let md = match String::from_utf8(buf) {
+        Ok(md) => md,
+        Err(_) => {
+            response.status(StatusCode::BAD_REQUEST);
+            return Ok(response
+                .body(Body::from("expected utf-8 markdown"))
+                .expect("invalid response builder"));
         }
+    };
+
+    debug!("showing unsaved buffer for {:?}", page);
+    if let Ok(mut clients) = clients.lock() {
+        clients.buffers.insert(page.clone(), md);
+        clients.reload(&page);
     } else {
+        error!("Internal error: mutex poisoned");
+    }
+    response.status(StatusCode::NO_CONTENT);
+    Ok(response
+        .body(Body::from(""))
+        .expect("invalid response builder"))
+}
+
+/// Id of the last event the browser has seen, from the Last-Event-ID header set
+/// by EventSource on reconnects or from the `since` query of the initial connect
+fn last_event_id(req: &Request<Body>) -> Option<u64> {
+    if let Some(id) = req.headers().get("Last-Event-ID") {
+        return id.to_str().ok().and_then(|id| id.parse().ok());
+    }
+    query_param(req, "since").and_then(|id| id.parse().ok())
+}
+
+fn is_markdown(path: &Path) -> bool {
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some(ext) => ext.eq_ignore_ascii_case("md") || ext.eq_ignore_ascii_case("markdown"),
+        None => false,
+    }
+}
+
+/// The markdown file, or directory for the index, a percent decoded request path refers to.
+/// Like on github, links to other markdown files and directories are rendered instead of
+/// served raw, as long as they are below the served directory
+fn page_path(cfg: &Cfg, path: &str) -> Option<PathBuf> {
+    if path == "/" {
+        return Some(cfg.md_file.clone());
+    }
+    let fullpath = contained_path(&cfg.root, path.strip_prefix('/')?)?;
+    if fullpath.is_dir() || (fullpath.is_file() && is_markdown(&fullpath)) {
+        Some(fullpath)
+    } else {
+        None
+    }
+}
+
+async fn events(
+    cfg: CfgPtr,
+    clients: ClientsPtr,
+    req: Request<Body>,
+) -> Result<Response<Body>, hyper::Error> {
+    let mut response = Response::builder();
+    response.header("Content-type", "text/event-stream");
+    response.header("Cache-Control", "no-cache");
+
+    let page = query_param(&req, "page")
+        .and_then(|page| page_path(&cfg, &page))
+        .unwrap_or_else(|| cfg.md_file.clone());
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let (id, changed, refreshed) = if let Ok(mut clients) = clients.lock() {
+        let id = clients.next_stream_id;
+        clients.next_stream_id += 1;
+        let changed = clients.changed(&page);
+        clients.streams.push(Stream { id, page, tx });
+        (id, changed, clients.refreshed)
+    } else {
+        error!("Internal error: mutex poisoned");
         return not_found();
     };
-    let title = String::from(
-        cfg.md_file
-            .to_str()
-            .unwrap_or(&format!("{:?}", cfg.md_file)),
+
+    let (mut body_tx, body) = Body::channel();
+    tokio::spawn(async move {
+        let mut preamble = format!("retry: {}\n\n", RECONNECT_DELAY);
+        // the page changed while the browser was disconnected
+        if let Some(seen) = last_event_id(&req) {
+            if seen < refreshed {
+                let missed = ServerEvent {
+                    id: refreshed,
+                    name: "refresh",
+                    data: String::new(),
+                };
+                preamble.push_str(&missed.encode());
+            } else if seen < changed {
+                let missed = ServerEvent {
+                    id: changed,
+                    name: "reload",
+                    data: String::new(),
+                };
+                preamble.push_str(&missed.encode());
+            }
+        }
+        if body_tx.send_data(preamble.into()).await.is_ok() {
+            loop {
+                let msg = match rx.recv().timeout(HEARTBEAT_INTERVAL).await {
+                    Ok(Some(event)) => event.encode(),
+                    Ok(None) => break,
+                    Err(_) => String::from(": heartbeat\n\n"),
+                };
+                if body_tx.send_data(msg.into()).await.is_err() {
+                    debug!("event stream closed by client");
+                    break;
+                }
+            }
+        }
+        if let Ok(mut clients) = clients.lock() {
+            clients.streams.retain(|stream| stream.id != id);
+        }
+    });
+
+    Ok(response.body(body).expect("invalid response builder"))
+}
+
+/// A rendered document
+struct Article {
+    /// the `#grup-content` container, with the table of contents sidebar if enabled
+    html: String,
+    /// nested lists of links to the headings
+    toc: String,
+    /// the title given in the front matter
+    title: Option<String>,
+    /// the local files referenced, relative to the page or the served directory
+    assets: Vec<String>,
+}
+
+/// Wrap rendered markdown into the container the stylesheet applies to,
+/// with a table of contents sidebar in front of it if `toc` is set
+fn article(
+    md: &str,
+    extensions: &render::Extensions,
+    inline_base: Option<&Path>,
+    toc: bool,
+) -> Article {
+    let rendered = render::markdown_to_html(md, extensions, inline_base);
+    let list = headings::toc(&rendered.headings);
+    let sidebar = if toc && !rendered.headings.is_empty() {
+        format!(
+            r#"<nav class="toc-sidebar"><details open><summary>Contents</summary>
+            {}
+            </details></nav>"#,
+            list
+        )
+    } else {
+        String::new()
+    };
+    let html = format!(
+        r#"<div id="grup-content">{sidebar}<article class="markdown-body">
+            {content}
+            </article></div>"#,
+        sidebar = sidebar,
+        content = rendered.html
     );
+    Article {
+        html,
+        toc: list,
+        title: rendered.title,
+        assets: rendered.assets,
+    }
+}
 
-    // push it all into a container
-    let document = format!(
+/// Render a markdown file into an `<article>`, or the index of a directory.
+/// None if it cannot be read
+async fn render_article(cfg: &Cfg, clients: &ClientsPtr, page: &Path) -> Option<Article> {
+    if page.is_dir() {
+        let index = index::index(&cfg.root, page).ok()?;
+        return Some(Article {
+            html: format!(r#"<div id="grup-content">{}</div>"#, index),
+            toc: String::new(),
+            title: None,
+            assets: Vec::new(),
+        });
+    }
+    let md = read_markdown(clients, page).await?;
+    let article = article(&md, &cfg.extensions, None, cfg.toc);
+    let assets = article
+        .assets
+        .iter()
+        .filter_map(|reference| asset_path(cfg, page, reference))
+        .collect();
+    if let Ok(mut clients) = clients.lock() {
+        clients.depend(page, assets);
+    }
+    Some(article)
+}
+
+/// The file a reference of `page` points to, resolved the way browsers do,
+/// None if it does not exist or lies outside of the served directory
+fn asset_path(cfg: &Cfg, page: &Path, reference: &str) -> Option<PathBuf> {
+    let relative = match reference.strip_prefix('/') {
+        Some(relative) => PathBuf::from(relative),
+        None => page.parent()?.strip_prefix(&cfg.root).ok()?.join(reference),
+    };
+    contained_path(&cfg.root, relative.to_str()?).filter(|path| path.is_file())
+}
+
+/// The markdown of a page, an editor's unsaved buffer or what was read from stdin
+/// takes the place of the file
+async fn read_markdown(clients: &ClientsPtr, page: &Path) -> Option<String> {
+    let buffer = clients
+        .lock()
+        .ok()
+        .and_then(|clients| clients.buffers.get(page).cloned());
+    if buffer.is_some() {
+        return buffer;
+    }
+    let mut file = File::open(page).await.ok()?;
+    let mut buf = String::new();
+    file.read_to_string(&mut buf).await.ok()?;
+    Some(buf)
+}
+
+/// Put an article into a complete html document.
+/// `stylesheet` and `script` are inserted into the head and after the article
+fn page(title: &str, stylesheet: &str, article: &str, script: &str) -> String {
+    format!(
         r#"<!DOCTYPE html>
          <html>
             <head>
@@ -119,74 +661,265 @@ async fn md_file(cfg: CfgPtr) -> Result<Response<Body>, hyper::Error> {
                     padding: 45px;
                     }}
                 </style>
-                <link rel="stylesheet" href="style.css">
+                {stylesheet}
                 <title>{title}</title>
             </head>
             <body>
-            <article class="markdown-body">
-            {content}
-            </article>
-            <script type="text/javascript">
-            function reload_check () {{
-                var xhr = new XMLHttpRequest();
-                xhr.overrideMimeType("text/plain");
-                xhr.timeout = {interval};
-                xhr.onreadystatechange = function () {{
-                    if(this.readyState === 4) {{
-                        if (this.status === 200) {{
-                            if (this.responseText == "yes") {{
-                                location.reload();
-                            }} else {{
-                                reload_check();
-                            }}
-                        }}
-                    }}
-                }}
-                xhr.ontimeout = function () {{
-                    reload_check();
-                }}
-                xhr.open("GET", "/update", true);
-                xhr.send();
-            }}
-            reload_check();
-            </script>
+            {article}
+            {script}
             </body>
         </html>"#,
-        title = title,
-        content = content,
-        interval = cfg.interval * 1000
+        title = render::escape_html(title),
+        stylesheet = stylesheet,
+        article = article,
+        script = script
+    )
+}
+
+/// Serve the rendered page. `path` is the request path it was requested with
+async fn md_file(
+    cfg: CfgPtr,
+    clients: ClientsPtr,
+    page_file: PathBuf,
+    path: &str,
+) -> Result<Response<Body>, hyper::Error> {
+    let mut response = Response::builder();
+    response.header("Content-type", "text/html");
+
+    // read before the file so a change while rendering is not missed
+    let last_id = clients.lock().map(|c| c.last_id).unwrap_or(0);
+    let article = match render_article(&cfg, &clients, &page_file).await {
+        Some(article) => article,
+        None => return not_found(),
+    };
+    let title = match (&article.title, page_file.strip_prefix(&cfg.root)) {
+        (Some(title), _) => Cow::Borrowed(title.as_str()),
+        (None, Ok(relative)) if relative != Path::new("") => relative.to_string_lossy(),
+        _ => page_file.to_string_lossy(),
+    };
+    let mut script = format!(
+        r#"<script type="text/javascript">
+            var grup = {{ last_event_id: {last_id}, interval: {interval}, page: "{page}" }};
+            {script}
+            </script>"#,
+        last_id = last_id,
+        interval = cfg.interval * 1000,
+        page = path
+            .replace('\\', "%5C")
+            .replace('"', "%22")
+            .replace('<', "%3C"),
+        script = LIVE_RELOAD_JS
+    );
+    if cfg.toc {
+        script.push_str(&format!(
+            r#"<script type="text/javascript">{}</script>"#,
+            TOC_JS
+        ));
+    }
+
+    script.push_str(&format!(
+        r#"<script type="text/javascript">{}</script>"#,
+        mermaid::MERMAID_INIT_JS
+    ));
+    script.push_str(theme::TOGGLE);
+
+    // push it all into a container
+    let stylesheet = format!(
+        r#"<link rel="stylesheet" href="/style.css">
+        {}"#,
+        cfg.theme.head()
     );
+    let document = match cfg.template {
+        Some(ref template_file) => {
+            let variables = template::Variables {
+                title: &render::escape_html(&title),
+                head: &stylesheet,
+                content: &article.html,
+                toc: &template::toc(&article.toc),
+                script: &script,
+            };
+            match template::render(template_file, &variables).await {
+                Ok(document) => document,
+                Err(e) => {
+                    // shown in place of the document, reloaded once the template is fixed
+                    error!("cannot render template {:?}: {}", template_file, e);
+                    let message = format!(
+                        r#"<article class="markdown-body"><h1>Template error</h1><pre>{}</pre></article>"#,
+                        render::escape_html(&e)
+                    );
+                    page(&title, &stylesheet, &message, &script)
+                }
+            }
+        }
+        None => page(&title, &stylesheet, &article.html, &script),
+    };
     Ok(response
         .body(Body::from(document))
         .expect("invalid response builder"))
 }
 
-async fn css() -> Result<Response<Body>, hyper::Error> {
+/// The page with stylesheet and local images embedded and without live reload,
+/// for saving as a single file
+async fn standalone(cfg: CfgPtr, clients: ClientsPtr) -> Result<Response<Body>, hyper::Error> {
+    let mut response = Response::builder();
+    response.header("Content-type", "text/html");
+    if let Some(md) = read_markdown(&clients, &cfg.md_file).await {
+        let title = cfg.md_file.to_string_lossy();
+        let document = export::standalone_page(&title, &md, &cfg.extensions, &cfg.root, cfg.theme);
+        return Ok(response
+            .body(Body::from(document))
+            .expect("invalid response builder"));
+    }
+    not_found()
+}
+
+/// The rendered article alone, fetched by the page to patch itself on updates
+async fn fragment(
+    cfg: CfgPtr,
+    clients: ClientsPtr,
+    path: &str,
+) -> Result<Response<Body>, hyper::Error> {
+    let mut response = Response::builder();
+    response.header("Content-type", "text/html");
+    response.header("Cache-Control", "no-cache, no-store, must-revalidate");
+    let page_file = match percent_decode_str(path)
+        .decode_utf8()
+        .ok()
+        .and_then(|path| page_path(&cfg, &path))
+    {
+        Some(page_file) => page_file,
+        None => return not_found(),
+    };
+    match render_article(&cfg, &clients, &page_file).await {
+        Some(article) => {
+            let mut html = article.html;
+            // the table of contents is only outside of the content in templates
+            if cfg.template.is_some() {
+                html.push_str(&template::toc(&article.toc));
+            }
+            Ok(response
+                .body(Body::from(html))
+                .expect("invalid response builder"))
+        }
+        None => not_found(),
+    }
+}
+
+async fn css(cfg: CfgPtr) -> Result<Response<Body>, hyper::Error> {
+    let mut response = Response::builder();
+    response.header("Content-type", "text/css");
+    let mut css = Vec::new();
+    if !cfg.replace_css {
+        css.extend_from_slice(DEFAULT_CSS);
+    }
+    css.extend_from_slice(GRUP_CSS);
+    if let Some(ref css_file) = cfg.css {
+        // read on every request, so changes show up on reload
+        match File::open(css_file).await {
+            Ok(mut file) => {
+                css.push(b'\n');
+                if let Err(e) = file.read_to_end(&mut css).await {
+                    warn!("cannot read {:?}: {}", css_file, e);
+                }
+            }
+            Err(e) => warn!("cannot open {:?}: {}", css_file, e),
+        }
+    }
+    Ok(response
+        .body(Body::from(css))
+        .expect("invalid response builder"))
+}
+
+async fn mermaid_js() -> Result<Response<Body>, hyper::Error> {
+    let mut response = Response::builder();
+    response.header("Content-type", "application/javascript");
+    // it is large and only changes with grup
+    response.header("Cache-Control", "max-age=86400");
+    Ok(response
+        .body(Body::from(mermaid::MERMAID_JS))
+        .expect("invalid response builder"))
+}
+
+async fn dark_css() -> Result<Response<Body>, hyper::Error> {
     let mut response = Response::builder();
     response.header("Content-type", "text/css");
     Ok(response
-        .body(Body::from(DEFAULT_CSS))
+        .body(Body::from(theme::DARK_CSS))
         .expect("invalid response builder"))
 }
 
+/// Guess a mime type from the first bytes of a file whose extension is unknown
+fn sniff_mime(buf: &[u8]) -> &'static str {
+    const SIGNATURES: &[(&[u8], &str)] = &[
+        (b"\x89PNG\r\n\x1a\n", "image/png"),
+        (b"\xff\xd8\xff", "image/jpeg"),
+        (b"GIF87a", "image/gif"),
+        (b"GIF89a", "image/gif"),
+        (b"%PDF-", "application/pdf"),
+        (b"BM", "image/bmp"),
+        (b"\x00\x00\x01\x00", "image/x-icon"),
+    ];
+    if let Some((_, mime)) = SIGNATURES.iter().find(|&&(sig, _)| buf.starts_with(sig)) {
+        return mime;
+    }
+    if buf.len() >= 12 && &buf[..4] == b"RIFF" && &buf[8..12] == b"WEBP" {
+        return "image/webp";
+    }
+    let sample = &buf[..buf.len().min(512)];
+    // a multibyte char may have been cut off at the end of the sample
+    let is_text = match std::str::from_utf8(sample) {
+        Ok(_) => true,
+        Err(e) => e.error_len().is_none(),
+    };
+    if !is_text {
+        return "application/octet-stream";
+    }
+    let text = String::from_utf8_lossy(sample);
+    let text = text.trim_start();
+    if text.starts_with("<svg") || (text.starts_with("<?xml") && text.contains("<svg")) {
+        "image/svg+xml"
+    } else {
+        "text/plain; charset=utf-8"
+    }
+}
+
+/// Mime type of a file by its extension, or by its content if the extension is unknown
+fn mime_type(path: &Path, content: &[u8]) -> &'static str {
+    match mime_guess::from_path(path).first_raw() {
+        Some(mime) => mime,
+        None => sniff_mime(content),
+    }
+}
+
+/// Resolve `relative` against the directory `base`.
+/// None if the path does not exist or lies outside of `base`
+fn contained_path(base: &Path, relative: &str) -> Option<PathBuf> {
+    // canonicalize returns Err if path does not exist.
+    let fullpath = base.join(relative).canonicalize().ok()?;
+    if fullpath.starts_with(base) {
+        Some(fullpath)
+    } else {
+        None
+    }
+}
+
 // Will only serve files relative to the md file
 async fn static_file(req: Request<Body>) -> Result<Response<Body>, hyper::Error> {
     let mut response = Response::builder();
     let cwd = std::env::current_dir().expect("no working dir");
     if req.uri().path().len() > 1 {
-        let mut fullpath = cwd.clone();
         // path() contains preceeding forward slash: /some/web/page
-        fullpath.push(&req.uri().path()[1..]);
-        // canonicalize returns Err if path does not exist.
-        if let Ok(fullpath) = fullpath.canonicalize() {
-            if fullpath.starts_with(&cwd) {
-                if let Ok(mut file) = File::open(&fullpath).await {
-                    let mut buf = String::new();
-                    if file.read_to_string(&mut buf).await.is_ok() {
-                        return Ok(response
-                            .body(Body::from(buf))
-                            .expect("invalid response builder"));
-                    }
+        if let Some(fullpath) = contained_path(&cwd, &req.uri().path()[1..]) {
+            if let Ok(mut file) = File::open(&fullpath).await {
+                let mut buf = Vec::new();
+                if file.read_to_end(&mut buf).await.is_ok() {
+                    let mime = mime_type(&fullpath, &buf);
+                    debug!("serving {:?} as {}", fullpath, mime);
+                    response.header("Content-type", mime);
+                    response.header("Content-Length", buf.len());
+                    return Ok(response
+                        .body(Body::from(buf))
+                        .expect("invalid response builder"));
                 }
             }
         }
@@ -197,106 +930,424 @@ async fn static_file(req: Request<Body>) -> Result<Response<Body>, hyper::Error>
 
 async fn router(
     cfg: CfgPtr,
-    updaters: SenderListPtr,
+    clients: ClientsPtr,
     req: Request<Body>,
 ) -> Result<Response<Body>, hyper::Error> {
-    match req.uri().path() {
-        "/update" => update(updaters).await,
-        "/" => md_file(cfg).await,
-        "/style.css" => css().await,
-        _ => {
-            if cfg.serve_static {
-                static_file(req).await
-            } else {
-                not_found()
+    // owned, as the request is handed on
+    let path = req.uri().path().to_owned();
+    match path.as_str() {
+        "/update" => update(clients).await,
+        "/events" => events(cfg, clients, req).await,
+        "/cursor" => cursor(cfg, clients, req).await,
+        "/standalone.html" => standalone(cfg, clients).await,
+        "/style.css" => css(cfg).await,
+        "/style-dark.css" => dark_css().await,
+        "/mermaid.min.js" => mermaid_js().await,
+        "/render" => render_buffer(cfg, clients, req).await,
+        "/fragment" => fragment(cfg, clients, "/").await,
+        _ if path.starts_with("/fragment/") => {
+            fragment(cfg, clients, &path["/fragment".len()..]).await
+        }
+        _ => match percent_decode_str(&path)
+            .decode_utf8()
+            .ok()
+            .and_then(|decoded| page_path(&cfg, &decoded))
+        {
+            Some(page_file) => md_file(cfg, clients, page_file, &path).await,
+            None if cfg.serve_static => static_file(req).await,
+            None => not_found(),
+        },
+    }
+}
+
+/// A change seen by the file watcher
+enum Change {
+    /// a markdown file changed, and whether the directory listing changed with it
+    Page(PathBuf, bool),
+    /// another file changed, which pages may reference
+    Asset(PathBuf),
+    /// the stylesheet or template changed
+    Surroundings,
+}
+
+/// Tell the browsers about a burst of changes at once
+fn apply_changes(cfg: &Cfg, clients: &ClientsPtr, changes: Vec<Change>) {
+    let mut refresh = false;
+    let mut pages = BTreeSet::new();
+    let mut assets = BTreeSet::new();
+    for change in changes {
+        match change {
+            Change::Surroundings => refresh = true,
+            Change::Asset(path) => {
+                assets.insert(path);
+            }
+            Change::Page(path, listing_changed) => {
+                if listing_changed {
+                    // the indices of the directories above list the file
+                    for dir in path
+                        .ancestors()
+                        .skip(1)
+                        .take_while(|dir| dir.starts_with(&cfg.root))
+                    {
+                        pages.insert(dir.to_owned());
+                    }
+                }
+                // pages linking to it are reloaded as well
+                assets.insert(path.clone());
+                pages.insert(path);
             }
         }
     }
+
+    if let Ok(mut clients) = clients.lock() {
+        let dependents: BTreeSet<PathBuf> = assets
+            .iter()
+            .flat_map(|asset| clients.dependents(asset))
+            .filter(|page| !pages.contains(page))
+            .collect();
+        for page in pages {
+            // saved or reverted, the file is what is shown from now on
+            clients.buffers.remove(&page);
+            clients.reload(&page);
+        }
+        for page in dependents {
+            debug!("reloading {:?} for the files it references", page);
+            clients.reload(&page);
+        }
+        if refresh {
+            clients.refresh();
+        }
+    } else {
+        error!("Internal error: mutex poisoned");
+    }
 }
 
-fn spawn_watcher(cfg: CfgPtr, updaters: SenderListPtr) -> notify::Result<RecommendedWatcher> {
-    let parent = cfg
-        .md_file
-        .parent()
-        .map(|x| {
-            if x == Path::new("") {
-                PathBuf::from(".")
-            } else {
-                PathBuf::from(x)
+/// Collect the changes until the files were quiet for `window`, as a single save
+/// often comes as several events and browsers should not see the file half written.
+/// Files changing all the time (e.g. logs or build output) delay the others by
+/// `MAX_DEBOUNCE_WINDOWS` windows at most
+fn spawn_debouncer(cfg: CfgPtr, clients: ClientsPtr, window: Duration) -> StdSender<Change> {
+    let (tx, rx) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        while let Ok(first) = rx.recv() {
+            let mut changes = vec![first];
+            let deadline = Instant::now() + window * MAX_DEBOUNCE_WINDOWS;
+            // on disconnect the remaining changes are still applied, then recv ends the loop
+            while let Some(left) = deadline.checked_duration_since(Instant::now()) {
+                match rx.recv_timeout(window.min(left)) {
+                    Ok(change) => changes.push(change),
+                    Err(_) => break,
+                }
             }
+            debug!("applying {} file changes", changes.len());
+            apply_changes(&cfg, &clients, changes);
+        }
+    });
+    tx
+}
+
+/// How the files are watched
+enum Backend {
+    /// os specific file watching (i.e. inotify on linux)
+    Native(RecommendedWatcher),
+    /// scanning the files for changes, for file systems without change notifications
+    Polling(PollWatcher),
+}
+
+/// The file watcher in use, which stops watching when dropped
+struct FileWatcher {
+    backend: Backend,
+    /// the watched directories, and whether their subdirectories are watched as well
+    watched: Vec<(PathBuf, bool)>,
+}
+
+impl FileWatcher {
+    fn new(backend: Backend) -> Self {
+        FileWatcher {
+            backend,
+            watched: Vec::new(),
+        }
+    }
+
+    fn watch(&mut self, dir: &Path, mode: RecursiveMode) -> notify::Result<()> {
+        match self.backend {
+            Backend::Native(ref mut watcher) => watcher.watch(dir, mode)?,
+            Backend::Polling(ref mut watcher) => watcher.watch(dir, mode)?,
+        }
+        let recursive = match mode {
+            RecursiveMode::Recursive => true,
+            RecursiveMode::NonRecursive => false,
+        };
+        self.watched.push((dir.to_owned(), recursive));
+        Ok(())
+    }
+
+    /// Whether changes of `file` are seen already
+    fn covers(&self, file: &Path) -> bool {
+        self.watched.iter().any(|(dir, recursive)| {
+            file.parent() == Some(dir.as_path()) || (*recursive && file.starts_with(dir))
         })
-        .unwrap_or_else(|| PathBuf::from("/"));
+    }
+
+    /// Watch the directory of `file` unless it is watched already.
+    /// Editors often replace files instead of writing them, which a watch on the file misses
+    fn watch_file(&mut self, file: &Path) -> notify::Result<()> {
+        match file.parent() {
+            Some(dir) if !self.covers(file) => self.watch(dir, RecursiveMode::NonRecursive),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// The handler of the file events, passing the changes on to the debouncer
+fn event_handler(
+    surroundings: Vec<PathBuf>,
+    tx: StdSender<Change>,
+) -> impl Fn(notify::Result<Event>) + Send + 'static {
+    // the handler may have to be Sync, the sender is not
+    let tx = Mutex::new(tx);
+    move |event: notify::Result<Event>| {
+        let event = match event {
+            Ok(ev) => ev,
+            Err(e) => {
+                error!("received file notifier error {:?}. Ignoring file event.", e);
+                return;
+            }
+        };
+
+        // Editors saving atomically write a temporary file and rename it over the original,
+        // or remove the original and create it anew. As directories are watched rather
+        // than the files, the new file is seen just like an edited one.
+        // Whether the directory index has to be updated:
+        let listing_changed = match event.kind {
+            EventKind::Create(_) => {
+                debug!("files created {:?}", &event.paths);
+                true
+            }
+            EventKind::Modify(ModifyKind::Name(_)) => {
+                debug!("files renamed {:?}", &event.paths);
+                true
+            }
+            EventKind::Modify(_) => {
+                debug!("files modified {:?}", &event.paths);
+                false
+            }
+            EventKind::Remove(_) => {
+                debug!("files removed {:?}", &event.paths);
+                true
+            }
+            _ => return,
+        };
 
-    // this uses os specific file watching where possible (i.e. inotify on linux)
-    // it forks of a mio event loop in the background and then calls the provided closure
+        // the pages are known by their canonical paths, which differ from the paths
+        // of the events where symlinks are involved
+        let paths: Vec<PathBuf> = event
+            .paths
+            .iter()
+            .map(|path| path.canonicalize().unwrap_or_else(|_| path.clone()))
+            .collect();
+
+        let mut changes = Vec::new();
+        if paths.iter().any(|path| surroundings.contains(path)) {
+            info!("stylesheet or template updated {:?}", &event.paths);
+            changes.push(Change::Surroundings);
+        }
+        // linked markdown files may be viewed as well, so every one is of interest
+        for path in paths {
+            if is_markdown(&path) {
+                info!("md file updated {:?}", path);
+                changes.push(Change::Page(path, listing_changed));
+            } else {
+                changes.push(Change::Asset(path));
+            }
+        }
+        if let Ok(tx) = tx.lock() {
+            for change in changes {
+                // ignore errors, the debouncer only stops with the process
+                let _ = tx.send(change);
+            }
+        }
+    }
+}
+
+/// Watch the served directory, and the directories of the stylesheet and template
+fn watch_paths(watcher: &mut FileWatcher, cfg: &Cfg) -> notify::Result<()> {
+    // when browsing a directory the subdirectories are watched as well
+    let browsing = cfg.md_file.is_dir();
+    let mode = if browsing {
+        RecursiveMode::Recursive
+    } else {
+        RecursiveMode::NonRecursive
+    };
+    watcher.watch(&cfg.root, mode)?;
+    for path in cfg.css.iter().chain(cfg.template.iter()) {
+        watcher.watch_file(path)?;
+    }
+    Ok(())
+}
+
+fn poll_watcher(
+    cfg: &Cfg,
+    surroundings: Vec<PathBuf>,
+    tx: StdSender<Change>,
+) -> notify::Result<FileWatcher> {
+    let interval = cfg.poll.unwrap_or(DEFAULT_POLL_INTERVAL);
+    let handler = Arc::new(Mutex::new(event_handler(surroundings, tx)));
+    let mut watcher = FileWatcher::new(Backend::Polling(PollWatcher::with_delay(
+        handler,
+        Duration::from_millis(interval),
+    )?));
+    watch_paths(&mut watcher, cfg)?;
+    info!("polling for file changes every {}ms", interval);
+    Ok(watcher)
+}
+
+/// Watch the files with the native file watcher, falling back to polling
+/// where the native one is unavailable (e.g. out of inotify watches)
+fn spawn_watcher(cfg: CfgPtr, clients: ClientsPtr) -> notify::Result<FileWatcher> {
+    // changes to these reload the whole page
+    let surroundings: Vec<PathBuf> = cfg.css.iter().chain(cfg.template.iter()).cloned().collect();
+    let tx = spawn_debouncer(cfg.clone(), clients, Duration::from_millis(cfg.debounce));
+
+    if cfg.poll.is_some() {
+        return poll_watcher(&cfg, surroundings, tx);
+    }
+    // this forks of a mio event loop in the background and then calls the handler
     // with the yielded events
-    let md_file_name = cfg.md_file.file_name().expect("path was `..`").to_owned();
-    let mut file_event_watcher: RecommendedWatcher =
-        Watcher::new_immediate(move |event: notify::Result<Event>| {
-            let event = match event {
-                Ok(ev) => ev,
-                Err(e) => {
-                    error!("received file notifier error {:?}. Ignoring file event.", e);
-                    return;
-                }
-            };
+    let native = RecommendedWatcher::new_immediate(event_handler(surroundings.clone(), tx.clone()))
+        .map(|watcher| FileWatcher::new(Backend::Native(watcher)))
+        .and_then(|mut watcher| watch_paths(&mut watcher, &cfg).map(|_| watcher));
+    match native {
+        Ok(watcher) => {
+            info!("watching for file changes with the native file watcher");
+            Ok(watcher)
+        }
+        Err(e) => {
+            warn!(
+                "cannot use the native file watcher ({:?}), polling for changes instead",
+                e
+            );
+            poll_watcher(&cfg, surroundings, tx)
+        }
+    }
+}
 
-            match event.kind {
-                EventKind::Create(_) => debug!("files created {:?}", &event.paths),
-                EventKind::Modify(_) => debug!("files modified {:?}", &event.paths),
-                _ => return,
-            };
+/// Read markdown documents from stdin into the buffer of `page`. Each document ends at a
+/// `delimiter` line or at the end of the input, and replaces the previous one in the browsers
+fn spawn_stdin_reader(page: PathBuf, delimiter: Option<String>, clients: ClientsPtr) {
+    let show = move |md: String| {
+        if let Ok(mut clients) = clients.lock() {
+            clients.buffers.insert(page.clone(), md);
+            clients.reload(&page);
+        } else {
+            error!("Internal error: mutex poisoned");
+        }
+    };
+    // an empty page until the first document is complete
+    show(String::new());
 
-            if event.paths.iter().any(|path| path.eq(&md_file_name)) {
-                info!("md file updated {:?}", cfg.md_file);
-                if let Ok(mut updaters) = updaters.lock() {
-                    for tx in updaters.drain(..) {
-                        // ignore errors
-                        let _ = tx.send(());
-                    }
-                } else {
-                    error!("Internal error: mutex poisoned");
+    // stdin blocks, so it gets a thread of its own
+    std::thread::spawn(move || {
+        let stdin = io::stdin();
+        let mut md = String::new();
+        for line in stdin.lock().lines() {
+            let line = match line {
+                Ok(line) => line,
+                Err(e) => {
+                    error!("cannot read stdin: {}", e);
+                    break;
                 }
+            };
+            if delimiter.as_ref() == Some(&line) {
+                debug!("read a document from stdin");
+                show(std::mem::take(&mut md));
+            } else {
+                md.push_str(&line);
+                md.push('\n');
             }
-        })?;
-
-    file_event_watcher.watch(&parent, RecursiveMode::NonRecursive)?;
-    Ok(file_event_watcher)
+        }
+        if delimiter.is_none() || !md.is_empty() {
+            show(md);
+        }
+        info!("end of stdin");
+    });
 }
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     env_logger::Builder::from_default_env().init();
-    let cfg = Arc::new(Cfg::from_args());
+    // structopt cannot have a subcommand next to the required markdown file, so dispatch by hand
+    if std::env::args_os()
+        .nth(1)
+        .is_some_and(|arg| arg == "export")
+    {
+        let export_cfg = export::ExportCfg::from_iter(std::env::args_os().skip(1));
+        export::export(&export_cfg)?;
+        return Ok(());
+    }
+    let mut cfg = Cfg::from_args();
+    let stdin = cfg.md_file == Path::new("-");
     let file = &cfg.md_file;
-    if let Some(parent) = file.parent() {
-        std::env::set_current_dir(parent)?;
+
+    if stdin {
+        // the markdown is served as if it was a file in the working directory
+        cfg.root = std::env::current_dir()?.canonicalize()?;
+        cfg.md_file = cfg.root.join(STDIN_PAGE);
     } else {
-        std::env::set_current_dir(std::path::Component::RootDir.as_os_str())?;
-    }
+        if !file.exists() {
+            return Err(io::Error::other(format!("No such file: {:?}", file)).into());
+        }
 
-    if !file.exists() {
-        return Err(
-            io::Error::new(io::ErrorKind::Other, format!("No such file: {:?}", file)).into(),
-        );
+        if !file.is_file() && !file.is_dir() {
+            return Err(io::Error::other(format!("No such file: {:?}", file)).into());
+        }
+
+        cfg.md_file = file.canonicalize()?;
+        cfg.root = if cfg.md_file.is_dir() {
+            cfg.md_file.clone()
+        } else {
+            cfg.md_file
+                .parent()
+                .map(PathBuf::from)
+                .unwrap_or_else(|| PathBuf::from(std::path::Component::RootDir.as_os_str()))
+        };
+    }
+    // relative to where grup was started, not to the served directory
+    for path in cfg.css.iter_mut().chain(cfg.template.iter_mut()) {
+        *path = path
+            .canonicalize()
+            .map_err(|e| io::Error::new(e.kind(), format!("Cannot open {:?}: {}", path, e)))?;
     }
+    std::env::set_current_dir(&cfg.root)?;
+    let cfg = Arc::new(cfg);
 
-    if !file.is_file() {
-        return Err(
-            io::Error::new(io::ErrorKind::Other, format!("No such file: {:?}", file)).into(),
+    let clients = Arc::new(Mutex::new(Clients::default()));
+    if stdin {
+        spawn_stdin_reader(
+            cfg.md_file.clone(),
+            cfg.delimiter.clone(),
+            Arc::clone(&clients),
         );
     }
-
-    let updaters = Arc::new(Mutex::new(Vec::new()));
-    // we just hold on to this, so the file watcher is killed when this function exits
-    let _watcher = spawn_watcher(cfg.clone(), Arc::clone(&updaters));
+    // the clients hold on to the watcher, to watch the files the pages reference as well
+    match spawn_watcher(cfg.clone(), Arc::clone(&clients)) {
+        Ok(watcher) => {
+            if let Ok(mut clients) = clients.lock() {
+                clients.watcher = Some(watcher);
+            }
+        }
+        Err(e) => error!(
+            "cannot watch for file changes, pages have to be reloaded by hand: {:?}",
+            e
+        ),
+    }
 
     let service = make_service_fn(|_| {
         let cfg = Arc::clone(&cfg);
-        let updaters = Arc::clone(&updaters);
+        let clients = Arc::clone(&clients);
         async {
             Ok::<_, hyper::Error>(service_fn(move |req| {
-                router(Arc::clone(&cfg), Arc::clone(&updaters), req)
+                router(Arc::clone(&cfg), Arc::clone(&clients), req)
             }))
         }
     });
@@ -308,3 +1359,34 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     server.await?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sniff_images() {
+        assert_eq!(sniff_mime(b"\x89PNG\r\n\x1a\n...."), "image/png");
+        assert_eq!(sniff_mime(b"\xff\xd8\xff\xe0"), "image/jpeg");
+        assert_eq!(sniff_mime(b"RIFF\x10\x00\x00\x00WEBPVP8 "), "image/webp");
+        assert_eq!(sniff_mime(b"  <svg xmlns=\"\">"), "image/svg+xml");
+        assert_eq!(
+            sniff_mime(b"<?xml version=\"1.0\"?>\n<svg>"),
+            "image/svg+xml"
+        );
+    }
+
+    #[test]
+    fn sniff_text() {
+        assert_eq!(
+            sniff_mime(b"<?xml version=\"1.0\"?>\n<a/>"),
+            "text/plain; charset=utf-8"
+        );
+        assert_eq!(sniff_mime(b""), "text/plain; charset=utf-8");
+        // a multibyte char cut off by the sample
+        let mut text = vec![b'a'; 511];
+        text.extend_from_slice("\u{e9}".as_bytes());
+        assert_eq!(sniff_mime(&text), "text/plain; charset=utf-8");
+        assert_eq!(sniff_mime(b"\x00\x01\xfe\xff"), "application/octet-stream");
+    }
+}
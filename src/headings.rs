@@ -0,0 +1,175 @@
+//! Heading ids and permalinks the way github generates them,
+//! and the table of contents built from the headings
+
+use comrak::nodes::{AstNode, NodeHtmlBlock, NodeValue};
+use comrak::{Anchorizer, Arena};
+
+use crate::render::escape_html;
+
+/// The link icon github shows when hovering a heading
+const OCTICON_LINK: &str = r#"<svg class="octicon octicon-link" viewBox="0 0 16 16" version="1.1" width="16" height="16" aria-hidden="true"><path fill-rule="evenodd" d="M4 9h1v1H4c-1.5 0-3-1.69-3-3.5S2.55 3 4 3h4c1.45 0 3 1.69 3 3.5 0 1.41-.91 2.72-2 3.25V8.59c.58-.45 1-1.27 1-2.09C10 5.22 8.98 4 8 4H4c-.98 0-2 1.22-2 2.5S3 9 4 9zm9-3h-1v1h1c1 0 2 1.22 2 2.5S13.98 12 13 12H9c-.98 0-2-1.22-2-2.5 0-.83.42-1.64 1-2.09V6.25c-1.09.53-2 1.84-2 3.25C6 11.31 7.55 13 9 13h4c1.45 0 3-1.69 3-3.5S14.5 6 13 6z"></path></svg>"#;
+
+/// A heading of the document
+#[derive(Debug, Clone)]
+pub struct Heading {
+    pub level: u32,
+    pub text: String,
+    /// the id of the heading, `#slug` links to it
+    pub slug: String,
+}
+
+/// The plain text of a node, as comrak collects it for its header ids
+fn collect_text<'a>(node: &'a AstNode<'a>, text: &mut Vec<u8>) {
+    match node.data.borrow().value {
+        NodeValue::Text(ref literal) | NodeValue::Code(ref literal) => {
+            text.extend_from_slice(literal)
+        }
+        NodeValue::LineBreak | NodeValue::SoftBreak => text.push(b' '),
+        _ => {
+            for child in node.children() {
+                collect_text(child, text);
+            }
+        }
+    }
+}
+
+/// Give every heading an id using github's slug algorithm (lowercased, punctuation stripped,
+/// duplicates suffixed with `-1`, `-2`, ...) and prepend a permalink to it.
+/// Returns the headings in document order
+pub fn add_anchors<'a>(arena: &'a Arena<AstNode<'a>>, root: &'a AstNode<'a>) -> Vec<Heading> {
+    let mut anchorizer = Anchorizer::new();
+    let mut headings = Vec::new();
+    for node in root.descendants() {
+        let level = match node.data.borrow().value {
+            NodeValue::Heading(ref heading) => heading.level,
+            _ => continue,
+        };
+        let mut text = Vec::new();
+        collect_text(node, &mut text);
+        let text = String::from_utf8_lossy(&text).into_owned();
+        let slug = anchorizer.anchorize(text.clone());
+
+        // the slug only consists of letters, numbers, `-` and `_`, so it needs no escaping
+        let anchor = format!(
+            r##"<a id="{slug}" class="anchor" aria-hidden="true" href="#{slug}">{icon}</a>"##,
+            slug = slug,
+            icon = OCTICON_LINK
+        );
+        node.prepend(arena.alloc(NodeValue::HtmlInline(anchor.into_bytes()).into()));
+        headings.push(Heading { level, text, slug });
+    }
+    headings
+}
+
+/// Nested lists of links to the headings
+pub fn toc(headings: &[Heading]) -> String {
+    let mut html = String::new();
+    // the levels of the currently open lists
+    let mut levels: Vec<u32> = Vec::new();
+    for heading in headings {
+        while levels.last().is_some_and(|&level| level > heading.level) {
+            html.push_str("</li></ul>");
+            levels.pop();
+        }
+        match levels.last() {
+            Some(&level) if level == heading.level => html.push_str("</li>\n"),
+            _ => {
+                html.push_str("<ul>\n");
+                levels.push(heading.level);
+            }
+        }
+        html.push_str(&format!(
+            r##"<li><a href="#{}">{}</a>"##,
+            heading.slug,
+            escape_html(&heading.text)
+        ));
+    }
+    for _ in levels {
+        html.push_str("</li></ul>\n");
+    }
+    html
+}
+
+/// Whether a node is a `[[_TOC_]]` paragraph or a `<!-- toc -->` comment
+pub fn is_toc_marker<'a>(node: &'a AstNode<'a>) -> bool {
+    match node.data.borrow().value {
+        NodeValue::Paragraph => {
+            let mut text = Vec::new();
+            collect_text(node, &mut text);
+            // the underscores are parsed as emphasis
+            let text = String::from_utf8_lossy(&text);
+            text.trim() == "[[TOC]]" || text.trim() == "[[_TOC_]]"
+        }
+        NodeValue::HtmlBlock(ref block) => {
+            let html = String::from_utf8_lossy(&block.literal).to_lowercase();
+            let html = html.trim();
+            html.starts_with("<!--")
+                && html.ends_with("-->")
+                && html[4..html.len() - 3].trim() == "toc"
+        }
+        _ => false,
+    }
+}
+
+/// Replace the table of contents markers with the list of headings
+pub fn expand_toc_markers<'a>(root: &'a AstNode<'a>, headings: &[Heading]) {
+    let markers: Vec<_> = root
+        .descendants()
+        .filter(|node| is_toc_marker(node))
+        .collect();
+    for node in markers {
+        for child in node.children().collect::<Vec<_>>() {
+            child.detach();
+        }
+        node.data.borrow_mut().value = NodeValue::HtmlBlock(NodeHtmlBlock {
+            block_type: 0,
+            literal: format!(r#"<nav class="markdown-toc">{}</nav>"#, toc(headings)).into_bytes(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn heading(level: u32, text: &str) -> Heading {
+        Heading {
+            level,
+            text: text.to_owned(),
+            slug: text.to_lowercase(),
+        }
+    }
+
+    #[test]
+    fn nested_toc() {
+        let headings = [
+            heading(1, "A"),
+            heading(2, "B"),
+            heading(2, "C"),
+            heading(1, "D"),
+        ];
+        assert_eq!(
+            toc(&headings),
+            "<ul>\n<li><a href=\"#a\">A</a><ul>\n<li><a href=\"#b\">B</a></li>\n\
+             <li><a href=\"#c\">C</a></li></ul></li>\n<li><a href=\"#d\">D</a></li></ul>\n"
+        );
+    }
+
+    #[test]
+    fn toc_starting_deeper() {
+        // h2 before the first h1 gets a list of its own
+        assert_eq!(
+            toc(&[heading(2, "A"), heading(1, "B")]),
+            "<ul>\n<li><a href=\"#a\">A</a></li></ul><ul>\n<li><a href=\"#b\">B</a></li></ul>\n"
+        );
+    }
+
+    #[test]
+    fn toc_escapes_text() {
+        assert_eq!(
+            toc(&[heading(3, "<T>")]),
+            "<ul>\n<li><a href=\"#<t>\">&lt;T&gt;</a></li></ul>\n"
+        );
+        assert_eq!(toc(&[]), "");
+    }
+}
@@ -0,0 +1,143 @@
+//! Offline syntax highlighting of fenced code blocks.
+//! Tokens are wrapped in the `pl-*` classes github uses, which the bundled stylesheet already colors.
+
+use comrak::nodes::{AstNode, NodeHtmlBlock, NodeValue};
+use lazy_static::lazy_static;
+use syntect::parsing::{ParseState, ScopeStack, SyntaxDefinition, SyntaxReference, SyntaxSet};
+use syntect::util::LinesWithEndings;
+
+use crate::render::escape_html;
+
+/// not part of the sublime default syntaxes
+const TOML_SYNTAX: &str = include_str!("../resource/TOML.sublime-syntax");
+
+lazy_static! {
+    static ref SYNTAX_SET: SyntaxSet = {
+        let mut builder = SyntaxSet::load_defaults_newlines().into_builder();
+        builder.add(
+            SyntaxDefinition::load_from_str(TOML_SYNTAX, true, None)
+                .expect("invalid bundled toml syntax"),
+        );
+        builder.build()
+    };
+}
+
+/// Languages github knows by names the sublime syntaxes lack, and the syntax used for them
+const ALIASES: &[(&str, &str)] = &[
+    ("shell", "sh"),
+    ("console", "sh"),
+    ("shell-session", "sh"),
+    ("shellsession", "sh"),
+    ("sh-session", "sh"),
+    ("c++", "cpp"),
+    ("golang", "go"),
+];
+
+/// The syntax for the language of a fenced code block
+fn find_syntax(lang: &str) -> Option<&'static SyntaxReference> {
+    let token = ALIASES
+        .iter()
+        .find(|(alias, _)| alias.eq_ignore_ascii_case(lang))
+        .map_or(lang, |&(_, token)| token);
+    SYNTAX_SET.find_syntax_by_token(token)
+}
+
+/// Scope prefixes and the github class used for them.
+/// The innermost scope with a match decides, earlier entries take precedence.
+const CLASSES: &[(&str, &str)] = &[
+    ("comment", "pl-c"),
+    ("punctuation.definition.comment", "pl-c"),
+    ("punctuation.definition.string", "pl-pds"),
+    ("string.regexp", "pl-sr"),
+    ("string", "pl-s"),
+    ("constant.character.escape", "pl-cce"),
+    ("constant", "pl-c1"),
+    ("variable.parameter", "pl-v"),
+    ("variable.language", "pl-c1"),
+    ("variable.function", "pl-en"),
+    ("variable.other", "pl-smi"),
+    ("keyword", "pl-k"),
+    ("storage", "pl-k"),
+    ("entity.name.tag", "pl-ent"),
+    ("entity.name", "pl-en"),
+    ("entity", "pl-e"),
+    ("support", "pl-c1"),
+    ("markup.heading", "pl-mh"),
+    ("markup.bold", "pl-mb"),
+    ("markup.italic", "pl-mi"),
+    ("markup.inserted", "pl-mi1"),
+    ("markup.deleted", "pl-md"),
+    ("markup.changed", "pl-mc"),
+    ("invalid", "pl-ii"),
+];
+
+fn class_for(stack: &ScopeStack) -> Option<&'static str> {
+    stack.as_slice().iter().rev().find_map(|scope| {
+        let name = scope.build_string();
+        CLASSES
+            .iter()
+            .find(|(prefix, _)| {
+                name.starts_with(prefix)
+                    && (name.len() == prefix.len() || name[prefix.len()..].starts_with('.'))
+            })
+            .map(|&(_, class)| class)
+    })
+}
+
+fn push_token(html: &mut String, text: &str, stack: &ScopeStack) {
+    if text.is_empty() {
+        return;
+    }
+    match class_for(stack) {
+        Some(class) => {
+            html.push_str(&format!(
+                r#"<span class="{}">{}</span>"#,
+                class,
+                escape_html(text)
+            ));
+        }
+        None => html.push_str(&escape_html(text)),
+    }
+}
+
+fn highlight(code: &str, syntax: &SyntaxReference) -> String {
+    let mut state = ParseState::new(syntax);
+    let mut stack = ScopeStack::new();
+    let mut html = String::with_capacity(code.len() * 2);
+    for line in LinesWithEndings::from(code) {
+        let mut pos = 0;
+        for (end, op) in state.parse_line(line, &SYNTAX_SET) {
+            push_token(&mut html, &line[pos..end], &stack);
+            pos = end;
+            stack.apply(&op);
+        }
+        push_token(&mut html, &line[pos..], &stack);
+    }
+    html
+}
+
+/// Replace code blocks in a known language by their highlighted html
+pub fn highlight_code_blocks<'a>(root: &'a AstNode<'a>) {
+    for node in root.descendants() {
+        let mut ast = node.data.borrow_mut();
+        let html = match ast.value {
+            NodeValue::CodeBlock(ref block) => {
+                let info = String::from_utf8_lossy(&block.info);
+                let syntax = match info.split_whitespace().next().and_then(find_syntax) {
+                    Some(syntax) => syntax,
+                    None => continue,
+                };
+                format!(
+                    r#"<div class="highlight highlight-{}"><pre>{}</pre></div>"#,
+                    syntax.scope.build_string().replace('.', "-"),
+                    highlight(&String::from_utf8_lossy(&block.literal), syntax)
+                )
+            }
+            _ => continue,
+        };
+        ast.value = NodeValue::HtmlBlock(NodeHtmlBlock {
+            block_type: 0,
+            literal: html.into_bytes(),
+        });
+    }
+}
@@ -0,0 +1,129 @@
+//! The index page listing the markdown files of a directory as a tree
+
+use std::collections::BTreeMap;
+use std::io;
+use std::path::Path;
+
+use walkdir::{DirEntry, WalkDir};
+
+use crate::is_markdown;
+use crate::render::escape_html;
+
+/// Directories that do not contain documentation, but lots of files to walk through
+const SKIPPED_DIRS: &[&str] = &["target", "node_modules"];
+
+#[derive(Default)]
+struct Tree {
+    dirs: BTreeMap<String, Tree>,
+    files: Vec<String>,
+}
+
+impl Tree {
+    fn insert(&mut self, relative: &Path) {
+        let mut components: Vec<String> = relative
+            .iter()
+            .map(|c| c.to_string_lossy().into_owned())
+            .collect();
+        let file = match components.pop() {
+            Some(file) => file,
+            None => return,
+        };
+        let mut dir = self;
+        for component in components {
+            dir = dir.dirs.entry(component).or_default();
+        }
+        dir.files.push(file);
+    }
+
+    /// Nested lists of links, `prefix` is the url of the directory with a trailing slash
+    fn to_html(&self, prefix: &str, html: &mut String) {
+        html.push_str("<ul>\n");
+        for (name, dir) in &self.dirs {
+            html.push_str(&format!(
+                "<li><details open><summary>{}/</summary>\n",
+                escape_html(name)
+            ));
+            dir.to_html(&format!("{}{}/", prefix, url_escape(name)), html);
+            html.push_str("</details></li>\n");
+        }
+        for name in &self.files {
+            html.push_str(&format!(
+                r#"<li><a href="{}{}">{}</a></li>"#,
+                prefix,
+                url_escape(name),
+                escape_html(name)
+            ));
+            html.push('\n');
+        }
+        html.push_str("</ul>\n");
+    }
+}
+
+/// Percent encode what would break a path segment in a link
+fn url_escape(segment: &str) -> String {
+    let mut escaped = String::with_capacity(segment.len());
+    for b in segment.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                escaped.push(b as char)
+            }
+            _ => escaped.push_str(&format!("%{:02X}", b)),
+        }
+    }
+    escaped
+}
+
+fn is_skipped(entry: &DirEntry) -> bool {
+    // never skip the root, even when it is called target or .something
+    if entry.depth() == 0 {
+        return false;
+    }
+    let name = entry.file_name().to_string_lossy();
+    name.starts_with('.') || (entry.file_type().is_dir() && SKIPPED_DIRS.contains(&name.as_ref()))
+}
+
+/// Render the tree of markdown files below `dir` into an `<article>`.
+/// Links are relative to `root`, which the files are served from
+pub fn index(root: &Path, dir: &Path) -> io::Result<String> {
+    let mut tree = Tree::default();
+    let walker = WalkDir::new(dir)
+        .sort_by(|a, b| a.file_name().cmp(b.file_name()))
+        .into_iter()
+        .filter_entry(|entry| !is_skipped(entry));
+    for entry in walker {
+        let entry = entry?;
+        if entry.file_type().is_file() && is_markdown(entry.path()) {
+            if let Ok(relative) = entry.path().strip_prefix(dir) {
+                tree.insert(relative);
+            }
+        }
+    }
+
+    let prefix = match dir.strip_prefix(root) {
+        Ok(relative) if relative != Path::new("") => format!(
+            "/{}/",
+            relative
+                .iter()
+                .map(|c| url_escape(&c.to_string_lossy()))
+                .collect::<Vec<_>>()
+                .join("/")
+        ),
+        _ => String::from("/"),
+    };
+    let title = dir
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| dir.to_string_lossy().into_owned());
+
+    let mut html = format!(
+        "<article class=\"markdown-body\">\n<h1>{}</h1>\n",
+        escape_html(&title)
+    );
+    if tree.dirs.is_empty() && tree.files.is_empty() {
+        html.push_str("<p>No markdown files found.</p>\n");
+    } else {
+        tree.to_html(&prefix, &mut html);
+    }
+    html.push_str("</article>");
+    Ok(html)
+}
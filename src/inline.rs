@@ -0,0 +1,136 @@
+//! Embedding of locally referenced images as data uris,
+//! so a single html file renders without grup or the files next to it,
+//! and collecting the local files a document references
+
+use std::fs;
+use std::path::Path;
+
+use comrak::nodes::{AstNode, NodeValue};
+use percent_encoding::percent_decode_str;
+
+use crate::{contained_path, mime_type};
+
+/// The decoded path of a url referring to a local file, None for remote urls
+fn local_path(url: &str) -> Option<String> {
+    if url.starts_with("//") || url.starts_with('#') || (url.contains(':') && !url.starts_with('/'))
+    {
+        // has a scheme (http:, data:, ...) or is no file at all
+        return None;
+    }
+    let path = url.split(['?', '#']).next()?;
+    let path = percent_decode_str(path).decode_utf8().ok()?;
+    Some(path.into_owned())
+}
+
+/// Read a local image into a data uri, None for remote urls and files outside of `base`
+fn data_uri(base: &Path, url: &str) -> Option<String> {
+    let path = local_path(url)?;
+    // links to the repository root (/docs/x.png) are relative to the markdown file for us
+    let fullpath = contained_path(base, path.trim_start_matches('/'))?;
+    let content = match fs::read(&fullpath) {
+        Ok(content) => content,
+        Err(e) => {
+            warn!("cannot inline {:?}: {}", fullpath, e);
+            return None;
+        }
+    };
+    debug!("inlining {:?}", fullpath);
+    Some(format!(
+        "data:{};base64,{}",
+        mime_type(&fullpath, &content),
+        base64::encode(&content)
+    ))
+}
+
+/// The byte ranges of the quoted values of an attribute in raw html, e.g. `src="x.png"`
+fn attribute_values(html: &str, attribute: &str) -> Vec<(usize, usize)> {
+    let pattern = format!("{}=", attribute);
+    let mut values = Vec::new();
+    let mut from = 0;
+    while let Some(pos) = html[from..].find(&pattern) {
+        let start = from + pos + pattern.len();
+        let quote = match html[start..].chars().next() {
+            Some(quote) if quote == '"' || quote == '\'' => quote,
+            _ => {
+                from = start;
+                continue;
+            }
+        };
+        match html[start + 1..].find(quote) {
+            Some(length) => {
+                values.push((start + 1, start + 1 + length));
+                from = start + length + 2;
+            }
+            None => break,
+        }
+    }
+    values
+}
+
+/// Replace the `src` attributes of raw html like `<img src="x.png" width="200">`
+fn inline_html(html: &[u8], base: &Path) -> Vec<u8> {
+    let html = String::from_utf8_lossy(html);
+    let mut inlined = String::with_capacity(html.len());
+    let mut plain = 0;
+    for (start, end) in attribute_values(&html, "src") {
+        let url = &html[start..end];
+        inlined.push_str(&html[plain..start]);
+        inlined.push_str(&data_uri(base, url).unwrap_or_else(|| url.to_owned()));
+        plain = end;
+    }
+    inlined.push_str(&html[plain..]);
+    inlined.into_bytes()
+}
+
+/// Embed the local images referenced by markdown and raw html
+pub fn inline_images<'a>(root: &'a AstNode<'a>, base: &Path) {
+    for node in root.descendants() {
+        match node.data.borrow_mut().value {
+            NodeValue::Image(ref mut link) => {
+                let uri = data_uri(base, &String::from_utf8_lossy(&link.url));
+                if let Some(uri) = uri {
+                    link.url = uri.into_bytes();
+                }
+            }
+            NodeValue::HtmlBlock(ref mut block) => {
+                block.literal = inline_html(&block.literal, base)
+            }
+            NodeValue::HtmlInline(ref mut literal) => *literal = inline_html(literal, base),
+            _ => (),
+        }
+    }
+}
+
+/// The local files referenced by images, links and raw html, as written in the document.
+/// Paths starting with `/` are relative to the served directory, the others to the document
+pub fn local_references<'a>(root: &'a AstNode<'a>) -> Vec<String> {
+    let mut urls = Vec::new();
+    for node in root.descendants() {
+        match node.data.borrow().value {
+            NodeValue::Image(ref link) | NodeValue::Link(ref link) => {
+                urls.push(String::from_utf8_lossy(&link.url).into_owned())
+            }
+            NodeValue::HtmlBlock(ref block) => html_references(&block.literal, &mut urls),
+            NodeValue::HtmlInline(ref literal) => html_references(literal, &mut urls),
+            _ => (),
+        }
+    }
+    let mut paths: Vec<String> = urls
+        .iter()
+        .filter_map(|url| local_path(url))
+        .filter(|path| !path.is_empty())
+        .collect();
+    paths.sort();
+    paths.dedup();
+    paths
+}
+
+/// The urls of `src` and `href` attributes, e.g. of images and stylesheets
+fn html_references(html: &[u8], urls: &mut Vec<String>) {
+    let html = String::from_utf8_lossy(html);
+    for attribute in &["src", "href"] {
+        for (start, end) in attribute_values(&html, attribute) {
+            urls.push(html[start..end].to_owned());
+        }
+    }
+}
@@ -0,0 +1,420 @@
+//! `$inline$` and `$$display$$` math, rendered to MathML on the server so browsers
+//! show it without any scripts or fonts.
+//!
+//! Commonmark would take the formulas apart (`a_1 * b_1` is emphasis to it), so they are
+//! cut out of the source before parsing and put back as MathML into the AST afterwards.
+
+use comrak::nodes::{AstNode, NodeHtmlBlock, NodeValue};
+use comrak::Arena;
+use latex2mathml::{latex_to_mathml, DisplayStyle};
+
+use crate::render::escape_html;
+
+/// The formulas are replaced by `START index END`, private use characters
+/// that survive parsing as text
+const START: char = '\u{E000}';
+const END: char = '\u{E001}';
+
+pub struct Formula {
+    tex: String,
+    display: bool,
+    /// what the document contained, restored where the formula turned out to be code
+    source: String,
+}
+
+fn placeholder(index: usize) -> String {
+    format!("{}{}{}", START, index, END)
+}
+
+/// The end of a formula starting at `start`, following pandoc's rules for single dollars:
+/// no whitespace inside the delimiters and no digit right after the closing one (`$5 and $10`)
+fn find_closing(line: &str, start: usize, delim: &str) -> Option<usize> {
+    let mut from = start;
+    while let Some(pos) = line[from..].find(delim) {
+        let end = from + pos;
+        let escaped = line[..end].ends_with('\\');
+        let space_before = line[..end].ends_with(char::is_whitespace);
+        let digit_after = line[end + delim.len()..].starts_with(|c: char| c.is_ascii_digit());
+        if end > start && !escaped && (delim == "$$" || (!space_before && !digit_after)) {
+            return Some(end);
+        }
+        from = end + delim.len();
+    }
+    None
+}
+
+/// Whether a line is a display formula on its own or the start of one spanning several lines,
+/// rather than text starting with a formula like `$$a$$ is the area`
+fn is_display_line(trimmed: &str) -> bool {
+    trimmed.starts_with("$$")
+        && find_closing(trimmed, 2, "$$").is_none_or(|end| end + 2 == trimmed.len())
+}
+
+/// Cut the inline formulas out of a line, skipping code spans and escaped dollars
+fn extract_inline(line: &str, formulas: &mut Vec<Formula>, out: &mut String) {
+    let bytes = line.as_bytes();
+    let mut plain = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 2,
+            b'`' => {
+                let run = bytes[i..].iter().take_while(|&&b| b == b'`').count();
+                let ticks = &line[i..i + run];
+                // the code span ends at the next run of the same length
+                let mut end = None;
+                let mut from = i + run;
+                while let Some(pos) = line[from..].find(ticks) {
+                    let candidate = from + pos;
+                    let length = bytes[candidate..]
+                        .iter()
+                        .take_while(|&&b| b == b'`')
+                        .count();
+                    if length == run {
+                        end = Some(candidate + run);
+                        break;
+                    }
+                    from = candidate + length;
+                }
+                i = end.unwrap_or(i + run);
+            }
+            b'$' => {
+                let delim = if line[i..].starts_with("$$") {
+                    "$$"
+                } else {
+                    "$"
+                };
+                let start = i + delim.len();
+                let opens = delim == "$$" || !line[start..].starts_with(char::is_whitespace);
+                match find_closing(line, start, delim).filter(|_| opens) {
+                    Some(end) => {
+                        out.push_str(&line[plain..i]);
+                        out.push_str(&placeholder(formulas.len()));
+                        formulas.push(Formula {
+                            tex: line[start..end].to_owned(),
+                            display: delim == "$$",
+                            source: line[i..end + delim.len()].to_owned(),
+                        });
+                        i = end + delim.len();
+                        plain = i;
+                    }
+                    None => i = start,
+                }
+            }
+            _ => i += 1,
+        }
+    }
+    out.push_str(&line[plain..]);
+}
+
+/// Replace the formulas of a document by placeholders, returning the document to be parsed
+pub fn extract(md: &str) -> (String, Vec<Formula>) {
+    let mut out = String::with_capacity(md.len());
+    let mut formulas = Vec::new();
+    // the marker of the fenced code block we are in
+    let mut fence: Option<&str> = None;
+    // the display formula spanning several lines we are in
+    let mut display: Option<Formula> = None;
+
+    for line in md.lines() {
+        let indent = line.len() - line.trim_start_matches(' ').len();
+        let trimmed = line.trim();
+
+        if let Some(mut formula) = display.take() {
+            if trimmed.is_empty() {
+                // display formulas cannot contain blank lines, so it was none after all
+                out.push_str(&formula.source);
+                out.push('\n');
+            } else {
+                formula.source.push('\n');
+                formula.source.push_str(line);
+                if let Some(tex) = trimmed.strip_suffix("$$") {
+                    formula.tex.push_str(tex);
+                    out.push_str(&placeholder(formulas.len()));
+                    // blank lines in place of the formula's, so the blocks below keep their lines
+                    out.push_str(&"\n".repeat(formula.source.lines().count()));
+                    formulas.push(formula);
+                } else {
+                    formula.tex.push_str(line);
+                    formula.tex.push('\n');
+                    display = Some(formula);
+                }
+                continue;
+            }
+        }
+
+        if let Some(marker) = fence {
+            if trimmed.starts_with(marker) && trimmed.trim_start_matches(&marker[..1]).is_empty() {
+                fence = None;
+            }
+        } else if indent < 4 && (trimmed.starts_with("```") || trimmed.starts_with("~~~")) {
+            let run = trimmed
+                .chars()
+                .take_while(|&c| c == trimmed.as_bytes()[0] as char)
+                .count();
+            fence = Some(&trimmed[..run]);
+        } else if indent < 4 && is_display_line(trimmed) {
+            match find_closing(trimmed, 2, "$$") {
+                Some(end) => {
+                    // a display formula on a single line
+                    out.push_str(&placeholder(formulas.len()));
+                    out.push('\n');
+                    formulas.push(Formula {
+                        tex: trimmed[2..end].to_owned(),
+                        display: true,
+                        source: line.to_owned(),
+                    });
+                }
+                None => {
+                    display = Some(Formula {
+                        tex: format!("{}\n", &trimmed[2..]),
+                        display: true,
+                        source: line.to_owned(),
+                    });
+                }
+            }
+            continue;
+        } else {
+            extract_inline(line, &mut formulas, &mut out);
+            out.push('\n');
+            continue;
+        }
+        out.push_str(line);
+        out.push('\n');
+    }
+    // an unclosed display formula is left as it was
+    if let Some(formula) = display {
+        out.push_str(&formula.source);
+        out.push('\n');
+    }
+    (out, formulas)
+}
+
+fn mathml(tex: &str, display: bool) -> String {
+    let style = if display {
+        DisplayStyle::Block
+    } else {
+        DisplayStyle::Inline
+    };
+    match latex_to_mathml(tex, style) {
+        Ok(mathml) => mathml,
+        Err(e) => {
+            warn!("cannot render formula {:?}: {}", tex, e);
+            format!(
+                r#"<code class="math-error" title="{}">{}</code>"#,
+                escape_html(&e.to_string()),
+                escape_html(tex)
+            )
+        }
+    }
+}
+
+/// A part of text containing placeholders
+enum Piece<'t> {
+    Text(&'t str),
+    Formula(&'t Formula),
+}
+
+/// Split text at the placeholders into the text between and the formulas
+fn pieces<'t>(source: &'t str, formulas: &'t [Formula]) -> Vec<Piece<'t>> {
+    let mut pieces = Vec::new();
+    let mut rest = source;
+    while let Some(start) = rest.find(START) {
+        let after = &rest[start + START.len_utf8()..];
+        let found = after.find(END).and_then(|end| {
+            let index: usize = after[..end].parse().ok()?;
+            Some((formulas.get(index)?, end))
+        });
+        match found {
+            Some((formula, end)) => {
+                pieces.push(Piece::Text(&rest[..start]));
+                pieces.push(Piece::Formula(formula));
+                rest = &after[end + END.len_utf8()..];
+            }
+            None => {
+                pieces.push(Piece::Text(&rest[..start + START.len_utf8()]));
+                rest = after;
+            }
+        }
+    }
+    pieces.push(Piece::Text(rest));
+    pieces
+}
+
+/// The text with the formulas put back as they were written
+fn restore(literal: &[u8], formulas: &[Formula]) -> Vec<u8> {
+    let literal = String::from_utf8_lossy(literal);
+    let mut restored = String::new();
+    for piece in pieces(&literal, formulas) {
+        match piece {
+            Piece::Text(text) => restored.push_str(text),
+            Piece::Formula(formula) => restored.push_str(&formula.source),
+        }
+    }
+    restored.into_bytes()
+}
+
+/// The html with the formulas rendered
+fn render_html(literal: &[u8], formulas: &[Formula]) -> Vec<u8> {
+    let literal = String::from_utf8_lossy(literal);
+    let mut html = String::new();
+    for piece in pieces(&literal, formulas) {
+        match piece {
+            Piece::Text(text) => html.push_str(text),
+            Piece::Formula(formula) => html.push_str(&mathml(&formula.tex, formula.display)),
+        }
+    }
+    html.into_bytes()
+}
+
+/// Put the formulas back into the parsed document as MathML, and render ```math blocks
+pub fn render_math<'a>(arena: &'a Arena<AstNode<'a>>, root: &'a AstNode<'a>, formulas: &[Formula]) {
+    let nodes: Vec<_> = root.descendants().collect();
+    for node in nodes {
+        let text = match node.data.borrow().value {
+            NodeValue::Text(ref literal) => Some(String::from_utf8_lossy(literal).into_owned()),
+            _ => None,
+        };
+        if let Some(text) = text {
+            if text.contains(START) {
+                // the text around and the formulas become siblings in place of the text
+                for piece in pieces(&text, formulas) {
+                    let value = match piece {
+                        Piece::Text("") => continue,
+                        Piece::Text(plain) => NodeValue::Text(plain.as_bytes().to_vec()),
+                        Piece::Formula(formula) => {
+                            let html = mathml(&formula.tex, formula.display);
+                            NodeValue::HtmlInline(html.into_bytes())
+                        }
+                    };
+                    node.insert_before(arena.alloc(value.into()));
+                }
+                node.detach();
+            }
+            continue;
+        }
+
+        let mut data = node.data.borrow_mut();
+        let replacement = match data.value {
+            NodeValue::Code(ref mut literal) => {
+                *literal = restore(literal, formulas);
+                None
+            }
+            NodeValue::Link(ref mut link) | NodeValue::Image(ref mut link) => {
+                link.url = restore(&link.url, formulas);
+                None
+            }
+            NodeValue::HtmlBlock(ref mut block) => {
+                block.literal = render_html(&block.literal, formulas);
+                None
+            }
+            NodeValue::HtmlInline(ref mut literal) => {
+                *literal = render_html(literal, formulas);
+                None
+            }
+            NodeValue::CodeBlock(ref mut block) => {
+                block.literal = restore(&block.literal, formulas);
+                if String::from_utf8_lossy(&block.info).trim() == "math" {
+                    let tex = String::from_utf8_lossy(&block.literal);
+                    Some(NodeValue::HtmlBlock(NodeHtmlBlock {
+                        block_type: 0,
+                        literal: format!(
+                            "<div class=\"math-display\">{}</div>\n",
+                            mathml(&tex, true)
+                        )
+                        .into_bytes(),
+                    }))
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        };
+        if let Some(value) = replacement {
+            data.value = value;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The formulas found in `md` and the document left to parse
+    fn extracted(md: &str) -> (Vec<(String, bool)>, String) {
+        let (out, formulas) = extract(md);
+        let formulas = formulas
+            .into_iter()
+            .map(|formula| (formula.tex, formula.display))
+            .collect();
+        (formulas, out)
+    }
+
+    #[test]
+    fn closing_dollar() {
+        assert_eq!(find_closing("$a$", 1, "$"), Some(2));
+        assert_eq!(find_closing("$a $", 1, "$"), None);
+        assert_eq!(find_closing("$5 and $10", 1, "$"), None);
+        assert_eq!(find_closing(r"$a\$b$", 1, "$"), Some(5));
+        assert_eq!(find_closing("$$", 1, "$"), None);
+        assert_eq!(find_closing("$$a $$", 2, "$$"), Some(4));
+    }
+
+    #[test]
+    fn inline_formulas() {
+        let (formulas, out) = extracted("area $a_1 * b_1$ and $c$.\n");
+        assert_eq!(
+            formulas,
+            vec![("a_1 * b_1".to_owned(), false), ("c".to_owned(), false)]
+        );
+        assert_eq!(
+            out,
+            format!("area {} and {}.\n", placeholder(0), placeholder(1))
+        );
+    }
+
+    #[test]
+    fn prices_are_no_formulas() {
+        let md = "costs $5 and $10\nor $ 3 $\n";
+        let (formulas, out) = extracted(md);
+        assert!(formulas.is_empty());
+        assert_eq!(out, md);
+    }
+
+    #[test]
+    fn code_is_left_alone() {
+        let md = "`$a$` and ``$b$``\n```\n$$\nx\n$$\n```\n";
+        let (formulas, out) = extracted(md);
+        assert!(formulas.is_empty());
+        assert_eq!(out, md);
+    }
+
+    #[test]
+    fn display_formulas() {
+        let (formulas, out) = extracted("$$x^2$$\n\n$$\na\nb\n$$\ntext\n");
+        assert_eq!(
+            formulas,
+            vec![("x^2".to_owned(), true), ("\na\nb\n".to_owned(), true)]
+        );
+        assert_eq!(
+            out,
+            format!("{}\n\n{}\n\n\n\ntext\n", placeholder(0), placeholder(1))
+        );
+    }
+
+    #[test]
+    fn text_starting_with_a_formula() {
+        let md = "$$a$$ is the area\n\nparagraph\n\n# Heading\n\ncost is 5$$\n";
+        let (formulas, out) = extracted(md);
+        assert_eq!(formulas, vec![("a".to_owned(), true)]);
+        assert_eq!(out, md.replacen("$$a$$", &placeholder(0), 1));
+    }
+
+    #[test]
+    fn unclosed_display_formula() {
+        let md = "$$ a\nb\n\n# Heading\n\nc $$\n";
+        let (formulas, out) = extracted(md);
+        assert!(formulas.is_empty());
+        assert_eq!(out, md);
+    }
+}
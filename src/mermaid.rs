@@ -0,0 +1,37 @@
+//! Mermaid diagrams in ```mermaid code blocks, drawn in the browser by the bundled mermaid
+
+use comrak::nodes::{AstNode, NodeHtmlBlock, NodeValue};
+
+use crate::render::escape_html;
+
+/// mermaid itself, served at /mermaid.min.js
+pub const MERMAID_JS: &[u8] = include_bytes!("../resource/mermaid.min.js");
+/// draws the diagrams of the page, loading mermaid when there are any
+pub const MERMAID_INIT_JS: &str = include_str!("../resource/mermaid-init.js");
+
+/// Replace mermaid code blocks by containers for the diagrams.
+/// They hold the source, which stays readable where no script runs (e.g. exported files)
+pub fn mermaid_blocks<'a>(root: &'a AstNode<'a>) {
+    for node in root.descendants() {
+        let mut ast = node.data.borrow_mut();
+        let html = match ast.value {
+            NodeValue::CodeBlock(ref block) => {
+                let info = String::from_utf8_lossy(&block.info);
+                if info.split_whitespace().next() != Some("mermaid") {
+                    continue;
+                }
+                let source = escape_html(&String::from_utf8_lossy(&block.literal));
+                // live reload keeps a drawn diagram as long as data-source is unchanged
+                format!(
+                    r#"<div class="mermaid-diagram" data-source="{source}"><pre><code>{source}</code></pre></div>"#,
+                    source = source
+                )
+            }
+            _ => continue,
+        };
+        ast.value = NodeValue::HtmlBlock(NodeHtmlBlock {
+            block_type: 0,
+            literal: html.into_bytes(),
+        });
+    }
+}
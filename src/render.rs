@@ -0,0 +1,251 @@
+//! The markdown to html pipeline: comrak parses the document into an AST,
+//! which is then amended by passes for the things github renders on top of commonmark
+
+use std::borrow::Cow;
+use std::path::Path;
+
+use comrak::nodes::{AstNode, NodeValue};
+use comrak::{Arena, ComrakOptions};
+use structopt::StructOpt;
+
+use crate::headings::{self, Heading};
+use crate::{alerts, emoji, front_matter, highlight, inline, math, mermaid};
+
+#[derive(Debug, StructOpt)]
+/// The github flavored markdown extensions are enabled by default, the others opt-in
+pub struct Extensions {
+    #[structopt(long = "no-tables", help = "disable gfm tables")]
+    no_tables: bool,
+    #[structopt(long = "no-strikethrough", help = "disable gfm ~~strikethrough~~")]
+    no_strikethrough: bool,
+    #[structopt(long = "no-autolinks", help = "disable gfm autolinking of urls")]
+    no_autolinks: bool,
+    #[structopt(long = "no-tasklists", help = "disable gfm task list items")]
+    no_tasklists: bool,
+    #[structopt(
+        long = "no-tagfilter",
+        help = "disable filtering of disallowed raw html tags like <script>"
+    )]
+    no_tagfilter: bool,
+    #[structopt(
+        long = "raw-html",
+        help = "render raw html written in the document, which may run scripts"
+    )]
+    raw_html: bool,
+    #[structopt(long = "footnotes", help = "enable footnotes[^1]")]
+    footnotes: bool,
+    #[structopt(long = "superscript", help = "enable ^superscript^")]
+    superscript: bool,
+    #[structopt(long = "description-lists", help = "enable description lists")]
+    description_lists: bool,
+    #[structopt(long = "smart", help = "enable smart punctuation")]
+    smart: bool,
+    #[structopt(
+        long = "no-highlight",
+        help = "disable syntax highlighting of fenced code blocks"
+    )]
+    no_highlight: bool,
+    #[structopt(
+        long = "no-math",
+        help = "disable rendering of $inline$ and $$display$$ math"
+    )]
+    no_math: bool,
+    #[structopt(long = "no-emoji", help = "disable :shortcode: emoji")]
+    no_emoji: bool,
+}
+
+impl Extensions {
+    fn comrak_options(&self) -> ComrakOptions {
+        ComrakOptions {
+            hardbreaks: true,
+            smart: self.smart,
+            // the passes emit html blocks, so comrak has to let them through.
+            // raw html from the document is dropped by omit_raw_html unless --raw-html is given
+            unsafe_: true,
+            ext_table: !self.no_tables,
+            ext_strikethrough: !self.no_strikethrough,
+            ext_autolink: !self.no_autolinks,
+            ext_tasklist: !self.no_tasklists,
+            ext_tagfilter: !self.no_tagfilter,
+            ext_footnotes: self.footnotes,
+            ext_superscript: self.superscript,
+            ext_description_lists: self.description_lists,
+            ..ComrakOptions::default()
+        }
+    }
+}
+
+/// Escape text for use in html content and attribute values
+pub fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Replace raw html written in the document the same way comrak does in safe mode
+fn omit_raw_html<'a>(root: &'a AstNode<'a>) {
+    const OMITTED: &[u8] = b"<!-- raw HTML omitted -->";
+    for node in root.descendants() {
+        if headings::is_toc_marker(node) {
+            continue;
+        }
+        match node.data.borrow_mut().value {
+            NodeValue::HtmlBlock(ref mut block) => block.literal = OMITTED.to_vec(),
+            NodeValue::HtmlInline(ref mut literal) => *literal = OMITTED.to_vec(),
+            _ => (),
+        }
+    }
+}
+
+/// Add `data-sourcepos="line"` to the first tag of a block's html
+fn annotate(html: &mut Vec<u8>, block: &[u8], line: u32) {
+    let name_length = block
+        .iter()
+        .skip(1)
+        .take_while(|b| b.is_ascii_alphanumeric())
+        .count();
+    // raw html may start with text, a comment or a closing tag
+    if line == 0 || block.first() != Some(&b'<') || name_length == 0 {
+        html.extend_from_slice(block);
+        return;
+    }
+    let (tag, rest) = block.split_at(1 + name_length);
+    html.extend_from_slice(tag);
+    html.extend_from_slice(format!(r#" data-sourcepos="{}""#, line).as_bytes());
+    html.extend_from_slice(rest);
+}
+
+/// Render block by block, marking every top level element with the line it starts at,
+/// so editors can scroll the preview to the cursor
+fn format_with_sourcepos<'a>(
+    arena: &'a Arena<AstNode<'a>>,
+    root: &'a AstNode<'a>,
+    options: &ComrakOptions,
+    html: &mut Vec<u8>,
+) {
+    // rendered together at the end, as comrak puts them into a single section
+    let footnotes = arena.alloc(NodeValue::Document.into());
+    for block in root.children().collect::<Vec<_>>() {
+        let (line, is_footnote) = {
+            let ast = block.data.borrow();
+            let is_footnote = matches!(ast.value, NodeValue::FootnoteDefinition(_));
+            (ast.start_line, is_footnote)
+        };
+        if is_footnote {
+            footnotes.append(block);
+            continue;
+        }
+        let mut block_html = Vec::new();
+        comrak::format_html(block, options, &mut block_html).expect("writing to a vec cannot fail");
+        annotate(html, &block_html, line);
+    }
+    if footnotes.first_child().is_some() {
+        comrak::format_html(footnotes, options, html).expect("writing to a vec cannot fail");
+    }
+}
+
+/// A rendered document
+pub struct Rendered {
+    pub html: String,
+    pub headings: Vec<Heading>,
+    /// the title given in the front matter
+    pub title: Option<String>,
+    /// the local files referenced, see `inline::local_references`
+    pub assets: Vec<String>,
+}
+
+/// Render markdown to html.
+/// With `inline_base` local images are embedded as data uris, resolved relative to that directory
+pub fn markdown_to_html(md: &str, ext: &Extensions, inline_base: Option<&Path>) -> Rendered {
+    let options = ext.comrak_options();
+    let arena = Arena::new();
+    let (front_matter, md) = match front_matter::split(md) {
+        Some((front_matter, md)) => (Some(front_matter), Cow::Owned(md)),
+        None => (None, Cow::Borrowed(md)),
+    };
+    let (md, formulas) = if ext.no_math {
+        (md.into_owned(), Vec::new())
+    } else {
+        math::extract(&md)
+    };
+    let root = comrak::parse_document(&arena, &md, &options);
+
+    if !ext.raw_html {
+        omit_raw_html(root);
+    }
+    if !ext.no_math {
+        math::render_math(&arena, root, &formulas);
+    }
+    // before the images are inlined, and once the formulas are out of the urls
+    let assets = inline::local_references(root);
+    alerts::render_alerts(&arena, root);
+    if let Some(base) = inline_base {
+        inline::inline_images(root, base);
+    }
+    mermaid::mermaid_blocks(root);
+    if !ext.no_highlight {
+        highlight::highlight_code_blocks(root);
+    }
+    // github derives the heading ids from the shortcodes, not the emoji
+    let mut headings = headings::add_anchors(&arena, root);
+    if !ext.no_emoji {
+        emoji::expand_shortcodes(root);
+        for heading in &mut headings {
+            if let Some(text) = emoji::expand(&heading.text) {
+                heading.text = text;
+            }
+        }
+    }
+    headings::expand_toc_markers(root, &headings);
+
+    let mut html = Vec::new();
+    if let Some(ref front_matter) = front_matter {
+        html.extend_from_slice(front_matter.html.as_bytes());
+    }
+    format_with_sourcepos(&arena, root, &options, &mut html);
+    Rendered {
+        html: String::from_utf8(html).expect("comrak produced invalid utf-8"),
+        headings,
+        title: front_matter.and_then(|front_matter| front_matter.title),
+        assets,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(md: &str) -> String {
+        let ext = Extensions::from_iter(&["grup"]);
+        markdown_to_html(md, &ext, None).html
+    }
+
+    #[test]
+    fn heading_ids_keep_shortcodes() {
+        let html = render("## :sparkles: Features\n");
+        assert!(html.contains(r#"id="sparkles-features""#), "{}", html);
+        assert!(html.contains("\u{2728} Features"), "{}", html);
+    }
+
+    #[test]
+    fn sourcepos_after_display_math() {
+        let html = render("intro\n\n$$\na\n+ b\n$$\n\nafter\n\n## Heading\n");
+        assert!(html.contains(r#"<p data-sourcepos="8">after"#), "{}", html);
+        assert!(html.contains(r#"<h2 data-sourcepos="10">"#), "{}", html);
+    }
+
+    #[test]
+    fn sourcepos_after_front_matter() {
+        let html = render("---\ntitle: x\n---\n\ntext\n");
+        assert!(html.contains(r#"<p data-sourcepos="5">text"#), "{}", html);
+    }
+}
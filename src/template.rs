@@ -0,0 +1,66 @@
+//! User supplied html templates for the served page
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use handlebars::Handlebars;
+use tokio_fs::File;
+use tokio_io::AsyncReadExt;
+
+/// What a template can place, everything is html already and is inserted as is
+pub struct Variables<'a> {
+    pub title: &'a str,
+    /// grup's stylesheets and theme script
+    pub head: &'a str,
+    /// the rendered document, patched in place on live reload
+    pub content: &'a str,
+    /// the table of contents, patched in place on live reload
+    pub toc: &'a str,
+    /// the live reload script
+    pub script: &'a str,
+}
+
+/// The table of contents the way it is handed to templates and updated by live reload
+pub fn toc(list: &str) -> String {
+    format!(r#"<nav id="grup-toc" class="grup-toc">{}</nav>"#, list)
+}
+
+/// Insert `html` in front of the first `tag`, or at `fallback` if there is none
+fn insert_before(document: &mut String, tag: &str, html: &str, fallback: usize) {
+    let position = document.find(tag).unwrap_or(fallback);
+    document.insert_str(position, html);
+}
+
+/// Render the template at `path`, which is read anew every time so changes show up on reload.
+/// Live reload needs grup's head and script, so they are added if the template leaves them out
+pub async fn render(path: &Path, variables: &Variables<'_>) -> Result<String, String> {
+    let mut template = String::new();
+    File::open(path)
+        .await
+        .map_err(|e| e.to_string())?
+        .read_to_string(&mut template)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let mut data = BTreeMap::new();
+    data.insert("title", variables.title);
+    data.insert("head", variables.head);
+    data.insert("content", variables.content);
+    data.insert("toc", variables.toc);
+    data.insert("script", variables.script);
+
+    let mut handlebars = Handlebars::new();
+    handlebars.register_escape_fn(handlebars::no_escape);
+    let mut document = handlebars
+        .render_template(&template, &data)
+        .map_err(|e| e.to_string())?;
+
+    if !document.contains(variables.head) {
+        insert_before(&mut document, "</head>", variables.head, 0);
+    }
+    if !document.contains(variables.script) {
+        let end = document.len();
+        insert_before(&mut document, "</body>", variables.script, end);
+    }
+    Ok(document)
+}
@@ -0,0 +1,75 @@
+//! The light, dark and auto (following the system preference) themes of the page
+
+use std::str::FromStr;
+
+/// github's dark palette, applied on top of the light stylesheet
+pub const DARK_CSS: &[u8] = include_bytes!("../resource/github-markdown-dark.css");
+const THEME_JS: &str = include_str!("../resource/theme.js");
+
+/// The button switching the theme in the served page
+pub const TOGGLE: &str =
+    r#"<button id="grup-theme-toggle" class="grup-theme-toggle" title="switch theme"></button>"#;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Theme {
+    Auto,
+    Light,
+    Dark,
+}
+
+impl Theme {
+    pub const NAMES: &'static [&'static str] = &["auto", "light", "dark"];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Theme::Auto => "auto",
+            Theme::Light => "light",
+            Theme::Dark => "dark",
+        }
+    }
+
+    /// The media query under which the dark stylesheet applies
+    fn dark_media(self) -> &'static str {
+        match self {
+            Theme::Auto => "(prefers-color-scheme: dark)",
+            Theme::Light => "not all",
+            Theme::Dark => "all",
+        }
+    }
+
+    /// The dark stylesheet and the switching script for the head of a served page
+    pub fn head(self) -> String {
+        format!(
+            r#"<link id="grup-dark" rel="stylesheet" href="/style-dark.css" media="{media}" data-theme="{theme}">
+            <script type="text/javascript">{script}</script>"#,
+            media = self.dark_media(),
+            theme = self.name(),
+            script = THEME_JS
+        )
+    }
+
+    /// The dark stylesheet embedded, for html files without grup behind them
+    pub fn inline_stylesheet(self) -> String {
+        if self == Theme::Light {
+            return String::new();
+        }
+        format!(
+            "<style media=\"{}\">\n{}\n</style>",
+            self.dark_media(),
+            String::from_utf8_lossy(DARK_CSS)
+        )
+    }
+}
+
+impl FromStr for Theme {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Theme::Auto),
+            "light" => Ok(Theme::Light),
+            "dark" => Ok(Theme::Dark),
+            _ => Err(format!("unknown theme {:?}", s)),
+        }
+    }
+}